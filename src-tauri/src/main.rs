@@ -5,18 +5,21 @@
 include!(concat!(env!("OUT_DIR"), "/version.rs"));
 
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fs::File;
 use std::io::{BufReader, Write};
 use std::path::PathBuf;
-use std::sync::{Arc, Mutex, atomic::{AtomicBool, Ordering}};
+use std::sync::{Arc, Condvar, Mutex, atomic::{AtomicBool, AtomicU64, Ordering}};
 use tauri::{State, Manager, AppHandle, GlobalShortcutManager, api::process::restart, SystemTray, SystemTrayMenu, SystemTrayMenuItem, CustomMenuItem, SystemTrayEvent};
 use uuid::Uuid;
+use rand::Rng;
 use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source, source::SineWave};
 use rdev::{listen, Event, EventType, Key};
 
-// Global stop flag for all playing sounds
-static STOP_ALL_FLAG: AtomicBool = AtomicBool::new(false);
+// Set by --safe-mode: start from defaults and leave the saved files untouched
+static SAFE_MODE: AtomicBool = AtomicBool::new(false);
+// Set by --debug-resources: emit resource-usage every few seconds
+static DEBUG_RESOURCES: AtomicBool = AtomicBool::new(false);
 
 // Global app handle for playing sounds from shortcuts
 static APP_HANDLE: std::sync::OnceLock<AppHandle> = std::sync::OnceLock::new();
@@ -27,18 +30,762 @@ lazy_static::lazy_static! {
 }
 
 // Global keybind registry for low-level keyboard hook
-// Maps keybind string (e.g., "Ctrl+A") to sound ID (or "STOP_ALL" for stop all)
+// Maps keybind string (e.g., "Ctrl+A") to sound ID (or "STOP_ALL" for stop all, "MUTE" for mute,
+// "QUICK_SEARCH" for focusing the search box, "GROUP_NEXT:<group>" for stepping through a group,
+// "SHOW_GROUP:<group>" for jumping to a group's view)
 lazy_static::lazy_static! {
     static ref KEYBIND_REGISTRY: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
     static ref PRESSED_KEYS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
 }
 
+const GROUP_NEXT_PREFIX: &str = "GROUP_NEXT:";
+const SHOW_GROUP_PREFIX: &str = "SHOW_GROUP:";
+
+// Per-group binds are controls, not sounds
+fn is_group_action(action: &str) -> bool {
+    action.starts_with(GROUP_NEXT_PREFIX) || action.starts_with(SHOW_GROUP_PREFIX)
+}
+
+// Index of the next member to play for each stepping group
+lazy_static::lazy_static! {
+    static ref GROUP_CURSORS: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
+}
+
+// How the low-level hook names keys: by physical position, or by the character
+// the active keyboard layout produces (so AZERTY/Dvorak binds match their letters)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+enum KeyMapping {
+    #[default]
+    Physical,
+    Layout,
+}
+
+static LAYOUT_KEY_MAPPING: AtomicBool = AtomicBool::new(false);
+
+// Set while one of MotoBoard's own text fields has focus (reported by the frontend)
+static TEXT_INPUT_FOCUSED: AtomicBool = AtomicBool::new(false);
+static IGNORE_WHEN_TYPING: AtomicBool = AtomicBool::new(false);
+
+// Ignore binds that are a bare letter or number, which misfire during normal typing
+static REQUIRE_MODIFIER_FOR_ALNUM: AtomicBool = AtomicBool::new(false);
+
+// Skip auto-repeat presses of a held key so a bind fires once per press
+static IGNORE_KEY_REPEAT: AtomicBool = AtomicBool::new(true);
+
+// Match numpad binds with NumLock off and keep CapsLock out of layout matching
+static NORMALIZE_LOCK_KEYS: AtomicBool = AtomicBool::new(true);
+
+// Unix millis of the last key event the listener saw
+static LAST_KEY_EVENT_MS: AtomicU64 = AtomicU64::new(0);
+// Held keys are forgotten after this long without any key event (0 = never), mirrored from settings.
+// A release missed during a lock screen or focus change would otherwise leave a phantom modifier.
+static STALE_KEY_TIMEOUT_MS: AtomicU64 = AtomicU64::new(5000);
+
+fn default_stale_key_timeout_ms() -> u32 {
+    5000
+}
+
+// Shorter than a held key's repeat delay would forget a modifier that's still down,
+// so anything but off is kept at least this long
+const MIN_STALE_KEY_TIMEOUT_MS: u32 = 2000;
+
+fn clamp_stale_key_timeout(ms: u32) -> u32 {
+    if ms == 0 {
+        0
+    } else {
+        ms.max(MIN_STALE_KEY_TIMEOUT_MS)
+    }
+}
+
+// Layout character last produced by each physical key, learned from key presses
+lazy_static::lazy_static! {
+    static ref LAYOUT_CHARS: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+}
+
 // Sound queue for sequential playback
 lazy_static::lazy_static! {
     static ref SOUND_QUEUE: Mutex<Vec<String>> = Mutex::new(Vec::new());
 }
 static QUEUE_PLAYING: AtomicBool = AtomicBool::new(false);
 
+// A sink currently playing a sound, so sounds can be stopped individually
+struct ActivePlayback {
+    id: u64,
+    sound_id: String,
+    // Requested output device, None for the system default
+    device: Option<String>,
+    sink: Arc<Sink>,
+    // Volume to restore when unmuting
+    volume: f32,
+    // Live loop switch for the main layer, see set_active_loop
+    looping: Option<LoopControl>,
+    // When it was registered, for the play-time cap
+    started: std::time::Instant,
+}
+
+// Shared playback state: the registry of live sinks plus the stop and mute switches.
+// Managed by Tauri next to AppState; hotkey and tray code reach it via audio_engine().
+#[derive(Default)]
+struct AudioEngine {
+    playbacks: Mutex<Vec<ActivePlayback>>,
+    next_playback_id: AtomicU64,
+    // Bumped by stop_all. A player stops once the generation it was queued in is over,
+    // so a stop can't be undone by the next trigger resetting a flag too early.
+    stop_generation: AtomicU64,
+    // Per-sound stop generations, bumped by stop_sounds the same way
+    sound_stop_generations: Mutex<HashMap<String, u64>>,
+    // Sinks keep playing at zero volume so they resume in place on unmute
+    muted: AtomicBool,
+    // Per-device level trims by device name, mirrored from settings
+    device_gains: Mutex<HashMap<String, f32>>,
+    // Pre-roll before a multi-device trigger starts, mirrored from settings (0 = off)
+    output_sync_ms: AtomicU64,
+    // What plays aimed at a missing primary device do, mirrored from settings
+    missing_device_behavior: Mutex<MissingDeviceBehavior>,
+    // Kept up to date by the device watcher
+    primary_status: Mutex<PrimaryDeviceStatus>,
+    // Requested stream formats by device name, mirrored from settings
+    device_configs: Mutex<HashMap<String, DeviceConfig>>,
+    // Longest a sound may play before it's stopped, mirrored from settings (0 = no limit)
+    max_play_secs: AtomicU64,
+    // Time a sink sits paused with audio queued before it starts, mirrored from settings (0 = off)
+    prebuffer_ms: AtomicU64,
+}
+
+// Stop generations a play was queued in: stop-all's, and its own sound's
+#[derive(Debug, Clone, Copy)]
+struct PlayGeneration {
+    all: u64,
+    sound: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct AutoStopped {
+    #[serde(rename = "soundId")]
+    sound_id: String,
+    device: Option<String>,
+    seconds: u64,
+}
+
+// Stream format a device should be opened with instead of its default
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct DeviceConfig {
+    #[serde(rename = "sampleRate")]
+    sample_rate: u32,
+}
+
+// What to do with plays for the saved primary device while it's unplugged
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+enum MissingDeviceBehavior {
+    #[default]
+    FallbackDefault,
+    Silence,
+    Queue,
+}
+
+#[derive(Default)]
+struct PrimaryDeviceStatus {
+    // Name of the saved primary device while it can't be found
+    unavailable: Option<String>,
+    fallback_warned: bool,
+    // Plays held back under MissingDeviceBehavior::Queue, with when they were triggered
+    pending: Vec<(std::time::Instant, PlaybackPlan)>,
+}
+
+type Engine = Arc<AudioEngine>;
+
+fn audio_engine() -> Option<Engine> {
+    APP_HANDLE.get().map(|app_handle| app_handle.state::<Engine>().inner().clone())
+}
+
+// Removes a playback from the registry when the playing thread finishes
+struct PlaybackGuard {
+    engine: Engine,
+    id: u64,
+}
+
+impl Drop for PlaybackGuard {
+    fn drop(&mut self) {
+        let now_idle = match self.engine.playbacks.lock() {
+            Ok(mut active) => {
+                active.retain(|p| p.id != self.id);
+                active.is_empty()
+            }
+            Err(_) => false,
+        };
+        if now_idle {
+            push_overlay_update(OverlayUpdate::Cleared);
+        }
+    }
+}
+
+impl PlaybackGuard {
+    fn set_loop_control(&self, looping: &LoopControl) {
+        if let Ok(mut active) = self.engine.playbacks.lock() {
+            if let Some(playback) = active.iter_mut().find(|p| p.id == self.id) {
+                playback.looping = Some(looping.clone());
+            }
+        }
+    }
+}
+
+// What happens when a new sound would exceed max_concurrent_sounds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+enum ConcurrencyPolicy {
+    #[default]
+    StopOldest,
+    StopNewest,
+    DropNew,
+}
+
+// Which of the sounds playing at shutdown come back on the next start
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+enum RestorePlaybackMode {
+    // Only loops, so a one-shot cut off mid-play doesn't blast on launch
+    #[default]
+    LoopsOnly,
+    All,
+}
+
+// What a left-click on the tray icon does
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+enum TrayClickAction {
+    #[default]
+    Show,
+    Toggle,
+    StopAll,
+    None,
+}
+
+// When session play counts are reset automatically
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+enum StatsResetPeriod {
+    #[default]
+    Never,
+    OnAppStart,
+    Daily,
+}
+
+// IDs of every sound sharing the given exclusive group
+fn exclusive_group_members(sounds: &HashMap<String, Sound>, group: &str) -> HashSet<String> {
+    sounds
+        .values()
+        .filter(|s| s.exclusive_group.as_deref() == Some(group))
+        .map(|s| s.id.clone())
+        .collect()
+}
+
+// Where a stop-all came from, for diagnosing "stop didn't work" reports
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum StopAllSource {
+    Rdev,
+    Shortcut,
+    Tray,
+    Command,
+    Panic,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct StopAllLogEntry {
+    source: StopAllSource,
+    // Unix millis
+    at: u64,
+    #[serde(rename = "stoppedSinks")]
+    stopped_sinks: usize,
+}
+
+const STOP_ALL_LOG_SIZE: usize = 50;
+
+// Most recent stop-all triggers, oldest first
+lazy_static::lazy_static! {
+    static ref STOP_ALL_LOG: Mutex<VecDeque<StopAllLogEntry>> = Mutex::new(VecDeque::new());
+}
+
+// Reserved panic bind, always registered and honored even while triggers are suspended
+const PANIC_KEYBIND: &str = "Ctrl+Alt+Shift+END";
+
+// Stop everything and log where the request came from
+fn stop_all_playback(source: StopAllSource) {
+    let stopped_sinks = audio_engine().map(|engine| engine.stop_all()).unwrap_or(0);
+    QUEUE_PLAYING.store(false, Ordering::SeqCst);
+
+    if let Ok(mut log) = STOP_ALL_LOG.lock() {
+        if log.len() == STOP_ALL_LOG_SIZE {
+            log.pop_front();
+        }
+        log.push_back(StopAllLogEntry {
+            source,
+            at: unix_now_millis(),
+            stopped_sinks,
+        });
+    }
+}
+
+fn toggle_muted() -> bool {
+    audio_engine().is_some_and(|engine| engine.toggle_muted())
+}
+
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+// Quit cleanly: stop audio, give sinks a moment to wind down, write everything to disk
+// and release the global shortcuts before exiting
+fn shutdown(app_handle: &AppHandle) {
+    if SHUTTING_DOWN.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    if let Some(engine) = audio_engine() {
+        save_resume_playback(&engine.resume_entries());
+        engine.stop_all();
+        let deadline = std::time::Instant::now() + std::time::Duration::from_millis(500);
+        while std::time::Instant::now() < deadline
+            && engine.playbacks.lock().map(|active| !active.is_empty()).unwrap_or(false)
+        {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+    }
+
+    let state: State<AppState> = app_handle.state();
+    if let Ok(audio_state) = state.lock() {
+        save_sounds(&audio_state.sounds);
+        save_settings(&audio_state);
+        save_stats(&audio_state.stats);
+    }
+
+    if let Ok(mut registry) = KEYBIND_REGISTRY.lock() {
+        registry.clear();
+    }
+    let _ = app_handle.global_shortcut_manager().unregister_all();
+
+    app_handle.exit(0);
+}
+
+impl AudioEngine {
+    // What's playing right now, one entry per sound
+    fn resume_entries(&self) -> Vec<ResumeEntry> {
+        let mut entries: Vec<ResumeEntry> = Vec::new();
+        if let Ok(active) = self.playbacks.lock() {
+            for playback in active.iter() {
+                let looping = playback.looping.as_ref().is_some_and(|l| l.enabled.load(Ordering::SeqCst));
+                match entries.iter_mut().find(|e| e.sound_id == playback.sound_id) {
+                    Some(entry) => entry.looping |= looping,
+                    None => entries.push(ResumeEntry {
+                        sound_id: playback.sound_id.clone(),
+                        looping,
+                    }),
+                }
+            }
+        }
+        entries
+    }
+
+    // Register a sink and set its starting volume (zero while muted), scaled by the device's trim
+    fn track(self: &Arc<Self>, sound_id: &str, device_name: Option<&str>, volume: f32, sink: &Arc<Sink>) -> PlaybackGuard {
+        let id = self.next_playback_id.fetch_add(1, Ordering::SeqCst);
+        let volume = volume * self.device_gain(device_name);
+        sink.set_volume(if self.is_muted() { 0.0 } else { volume });
+        if let Ok(mut active) = self.playbacks.lock() {
+            active.push(ActivePlayback {
+                id,
+                sound_id: sound_id.to_string(),
+                device: device_name.map(|d| d.to_string()),
+                sink: sink.clone(),
+                volume,
+                looping: None,
+                started: std::time::Instant::now(),
+            });
+        }
+        PlaybackGuard { engine: self.clone(), id }
+    }
+
+    // Stop sinks that have run past the play-time cap, whatever player, effects or loop state
+    // they have. Their playing threads see the empty sink and unregister as usual.
+    fn stop_overdue(&self) {
+        let max_secs = self.max_play_secs.load(Ordering::SeqCst);
+        if max_secs == 0 {
+            return;
+        }
+        let mut stopped = Vec::new();
+        if let Ok(active) = self.playbacks.lock() {
+            for playback in active.iter() {
+                if !playback.sink.empty() && playback.started.elapsed().as_secs() >= max_secs {
+                    playback.sink.stop();
+                    stopped.push(AutoStopped {
+                        sound_id: playback.sound_id.clone(),
+                        device: playback.device.clone(),
+                        seconds: max_secs,
+                    });
+                }
+            }
+        }
+        if let Some(app_handle) = APP_HANDLE.get() {
+            for stopped in stopped {
+                let _ = app_handle.emit_all("playback-auto-stopped", stopped);
+            }
+        }
+    }
+
+    // Level trim for an output, 1.0 when none is set. None is the system default device.
+    fn device_gain(&self, device_name: Option<&str>) -> f32 {
+        let Ok(gains) = self.device_gains.lock() else {
+            return 1.0;
+        };
+        if gains.is_empty() {
+            return 1.0;
+        }
+        let Some(name) = device_name.map(|d| d.to_string()).or_else(default_output_device_name) else {
+            return 1.0;
+        };
+        gains
+            .iter()
+            .find(|(device, _)| device.eq_ignore_ascii_case(&name))
+            .map(|(_, gain)| *gain)
+            .unwrap_or(1.0)
+    }
+
+    fn generation(&self) -> u64 {
+        self.stop_generation.load(Ordering::SeqCst)
+    }
+
+    fn stopped_since(&self, generation: u64) -> bool {
+        self.generation() != generation
+    }
+
+    fn sound_generation(&self, sound_id: &str) -> u64 {
+        self.sound_stop_generations
+            .lock()
+            .map(|generations| generations.get(sound_id).copied().unwrap_or(0))
+            .unwrap_or(0)
+    }
+
+    fn play_generation(&self, sound_id: &str) -> PlayGeneration {
+        PlayGeneration { all: self.generation(), sound: self.sound_generation(sound_id) }
+    }
+
+    // Whether a stop-all or a stop of this sound came after the play was queued
+    fn play_stopped(&self, sound_id: &str, generation: PlayGeneration) -> bool {
+        self.stopped_since(generation.all) || self.sound_generation(sound_id) != generation.sound
+    }
+
+    // Stop everything: running sinks stop now, queued and delayed plays never start.
+    // Returns how many sinks were playing.
+    fn stop_all(&self) -> usize {
+        PLAYBACK_POOL.clear_pending();
+        self.stop_generation.fetch_add(1, Ordering::SeqCst);
+        let Ok(active) = self.playbacks.lock() else {
+            return 0;
+        };
+        for playback in active.iter() {
+            playback.sink.stop();
+        }
+        active.len()
+    }
+
+    // Enforce the global cap on simultaneous sounds before a new one starts.
+    // Returns false when the new trigger should be dropped instead.
+    fn make_room_for_sound(&self, sound_id: &str, max_sounds: usize, policy: ConcurrencyPolicy) -> bool {
+        if max_sounds == 0 {
+            return true;
+        }
+        let active = match self.playbacks.lock() {
+            Ok(active) => active,
+            Err(_) => return true,
+        };
+
+        // Other playing sounds, ordered by when they started
+        let mut playing: Vec<&str> = Vec::new();
+        for playback in active.iter() {
+            if playback.sound_id != sound_id && !playback.sink.empty() && !playing.contains(&playback.sound_id.as_str()) {
+                playing.push(&playback.sound_id);
+            }
+        }
+        if playing.len() < max_sounds {
+            return true;
+        }
+
+        let excess = playing.len() + 1 - max_sounds;
+        let to_stop: Vec<&str> = match policy {
+            ConcurrencyPolicy::DropNew => return false,
+            ConcurrencyPolicy::StopOldest => playing.iter().take(excess).copied().collect(),
+            ConcurrencyPolicy::StopNewest => playing.iter().rev().take(excess).copied().collect(),
+        };
+        for playback in active.iter().filter(|p| to_stop.contains(&p.sound_id.as_str())) {
+            playback.sink.stop();
+        }
+        true
+    }
+
+    // Stop every active sink playing one of the given sounds. Plays of them that are still
+    // queued, delayed or opening their stream see the bumped generation and never start.
+    fn stop_sounds(&self, sound_ids: &HashSet<String>) {
+        if let Ok(mut generations) = self.sound_stop_generations.lock() {
+            for sound_id in sound_ids {
+                *generations.entry(sound_id.clone()).or_insert(0) += 1;
+            }
+        }
+        if let Ok(active) = self.playbacks.lock() {
+            for playback in active.iter().filter(|p| sound_ids.contains(&p.sound_id)) {
+                playback.sink.stop();
+            }
+        }
+    }
+
+    // Stop every active sink on one output device, returning how many were stopped.
+    // Sinks on the default device also match the default device's name.
+    fn stop_device(&self, device_name: &str) -> usize {
+        let target = device_name.to_lowercase();
+        let default_name = default_output_device_name().map(|n| n.to_lowercase());
+
+        let mut stopped = 0;
+        if let Ok(active) = self.playbacks.lock() {
+            for playback in active.iter() {
+                let on_device = match &playback.device {
+                    Some(device) => device.to_lowercase() == target,
+                    None => default_name.as_deref() == Some(target.as_str()),
+                };
+                if on_device {
+                    playback.sink.stop();
+                    stopped += 1;
+                }
+            }
+        }
+        stopped
+    }
+
+    // Flip the live loop switch of a playing sound. False if it isn't playing.
+    fn set_loop(&self, sound_id: &str, enabled: bool) -> bool {
+        let Ok(active) = self.playbacks.lock() else {
+            return false;
+        };
+        let mut found = false;
+        for looping in active
+            .iter()
+            .filter(|p| p.sound_id == sound_id)
+            .filter_map(|p| p.looping.as_ref())
+        {
+            looping.set(enabled);
+            found = true;
+        }
+        found
+    }
+
+    fn is_muted(&self) -> bool {
+        self.muted.load(Ordering::SeqCst)
+    }
+
+    // Mute or unmute every active sink, updating the tray checkmark and notifying the UI
+    fn set_muted(&self, muted: bool) {
+        self.muted.store(muted, Ordering::SeqCst);
+        if let Ok(active) = self.playbacks.lock() {
+            for playback in active.iter() {
+                playback.sink.set_volume(if muted { 0.0 } else { playback.volume });
+            }
+        }
+        if let Some(app_handle) = APP_HANDLE.get() {
+            let _ = app_handle.tray_handle().get_item("mute").set_selected(muted);
+            let _ = app_handle.emit_all("mute-changed", muted);
+        }
+    }
+
+    fn toggle_muted(&self) -> bool {
+        let muted = !self.is_muted();
+        self.set_muted(muted);
+        muted
+    }
+}
+
+// Bounded worker pool for player threads, so rapid triggering queues plays
+// instead of spawning an unbounded number of threads and output streams
+type PlaybackJob = Box<dyn FnOnce() + Send + 'static>;
+
+const DEFAULT_PLAYBACK_THREADS: usize = 16;
+
+struct PoolState {
+    jobs: VecDeque<PlaybackJob>,
+    size: usize,
+    workers: usize,
+    idle: usize,
+}
+
+struct PlaybackPool {
+    state: Mutex<PoolState>,
+    job_ready: Condvar,
+}
+
+impl PlaybackPool {
+    fn new(size: usize) -> Self {
+        Self {
+            state: Mutex::new(PoolState {
+                jobs: VecDeque::new(),
+                size,
+                workers: 0,
+                idle: 0,
+            }),
+            job_ready: Condvar::new(),
+        }
+    }
+
+    // Returns false when every worker is busy and the job has to wait in the queue
+    fn execute(&'static self, job: PlaybackJob) -> bool {
+        let mut state = match self.state.lock() {
+            Ok(s) => s,
+            Err(_) => return false,
+        };
+        state.jobs.push_back(job);
+
+        // Grow lazily: only start a worker if every existing one is busy
+        let mut waiting = state.jobs.len() > state.idle;
+        if waiting && state.workers < state.size {
+            state.workers += 1;
+            let spawned = std::thread::Builder::new()
+                .name(format!("playback_worker_{}", state.workers))
+                .spawn(move || self.worker_loop());
+            if spawned.is_err() {
+                state.workers -= 1;
+            } else {
+                waiting = false;
+            }
+        }
+        self.job_ready.notify_one();
+        !waiting
+    }
+
+    fn worker_loop(&self) {
+        loop {
+            let job = {
+                let mut state = match self.state.lock() {
+                    Ok(s) => s,
+                    Err(_) => return,
+                };
+                loop {
+                    // Shrink when the configured size was lowered
+                    if state.workers > state.size {
+                        state.workers -= 1;
+                        return;
+                    }
+                    if let Some(job) = state.jobs.pop_front() {
+                        break job;
+                    }
+                    state.idle += 1;
+                    state = match self.job_ready.wait(state) {
+                        Ok(s) => s,
+                        Err(_) => return,
+                    };
+                    state.idle -= 1;
+                }
+            };
+            job();
+        }
+    }
+
+    fn resize(&self, size: usize) {
+        if let Ok(mut state) = self.state.lock() {
+            state.size = size.max(1);
+        }
+        self.job_ready.notify_all();
+    }
+
+    // Drop plays that haven't started yet
+    fn clear_pending(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.jobs.clear();
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref PLAYBACK_POOL: PlaybackPool = PlaybackPool::new(DEFAULT_PLAYBACK_THREADS);
+}
+
+// A play had to queue behind busy workers. It still starts once one frees up.
+fn pool_full_error() -> PlaybackError {
+    PlaybackError::PoolFull(
+        "All playback threads are busy, so this sound will start when one frees up. \
+         Stop long or looping sounds, or raise the playback thread count in settings."
+            .to_string(),
+    )
+}
+
+// Structured playback failure, reported to the frontend from background threads
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "camelCase")]
+enum PlaybackError {
+    File(String),
+    Decode(String),
+    Device(String),
+    // Device held by another app (WASAPI exclusive mode) or gone
+    DeviceBusy(String),
+    Webhook(String),
+    // Opening the file took longer than the configured timeout (slow network share)
+    FileTimeout(String),
+    // Not a format MotoBoard can play
+    Unsupported(String),
+    // Every playback worker is busy (long or looping sounds), so the play is waiting its turn
+    PoolFull(String),
+}
+
+impl PlaybackError {
+    fn kind(&self) -> &'static str {
+        match self {
+            PlaybackError::File(_) => "file",
+            PlaybackError::Decode(_) => "decode",
+            PlaybackError::Device(_) => "device",
+            PlaybackError::DeviceBusy(_) => "deviceBusy",
+            PlaybackError::Webhook(_) => "webhook",
+            PlaybackError::FileTimeout(_) => "fileTimeout",
+            PlaybackError::Unsupported(_) => "unsupported",
+            PlaybackError::PoolFull(_) => "poolFull",
+        }
+    }
+}
+
+impl std::fmt::Display for PlaybackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlaybackError::File(msg)
+            | PlaybackError::Decode(msg)
+            | PlaybackError::Device(msg)
+            | PlaybackError::DeviceBusy(msg)
+            | PlaybackError::Webhook(msg)
+            | PlaybackError::FileTimeout(msg)
+            | PlaybackError::Unsupported(msg)
+            | PlaybackError::PoolFull(msg) => {
+                write!(f, "{}", msg)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct PlaybackErrorReport {
+    #[serde(rename = "soundId")]
+    sound_id: String,
+    kind: String,
+    message: String,
+}
+
+// Most recent playback failure, for "I pressed my key and nothing happened"
+lazy_static::lazy_static! {
+    static ref LAST_PLAYBACK_ERROR: Mutex<Option<PlaybackErrorReport>> = Mutex::new(None);
+}
+
+// Record a playback failure and notify the frontend
+fn report_playback_error(sound_id: &str, error: PlaybackError) {
+    eprintln!("Playback error for {}: {}", sound_id, error);
+    let report = PlaybackErrorReport {
+        sound_id: sound_id.to_string(),
+        kind: error.kind().to_string(),
+        message: error.to_string(),
+    };
+    if let Ok(mut last) = LAST_PLAYBACK_ERROR.lock() {
+        *last = Some(report.clone());
+    }
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let _ = app_handle.emit_all("playback-error", report);
+    }
+}
+
 // Convert rdev Key to string representation
 fn key_to_string(key: Key) -> Option<String> {
     match key {
@@ -141,54 +888,325 @@ fn key_to_string(key: Key) -> Option<String> {
         Key::ShiftLeft | Key::ShiftRight => Some("SHIFT".to_string()),
         Key::Alt | Key::AltGr => Some("ALT".to_string()),
         Key::MetaLeft | Key::MetaRight => Some("META".to_string()),
+        // VK_CLEAR, what numpad 5 sends with NumLock off
+        #[cfg(windows)]
+        Key::Unknown(12) => Some("CLEAR".to_string()),
         _ => None,
     }
 }
 
-// Check if current pressed keys match a registered keybind
-fn check_keybind_match() {
-    let pressed = PRESSED_KEYS.lock().unwrap();
-    let registry = KEYBIND_REGISTRY.lock().unwrap();
+// Keybind triggers can be suspended (e.g. while assigning binds in the editor)
+static TRIGGERS_SUSPENDED: AtomicBool = AtomicBool::new(false);
 
-    // Build the current keybind string from pressed keys
-    let mut parts: Vec<&str> = Vec::new();
-    let mut main_key: Option<&str> = None;
+lazy_static::lazy_static! {
+    // Auto-resume time for a timed suspension, None while suspended until resumed
+    static ref TRIGGERS_RESUME_AT: Mutex<Option<std::time::Instant>> = Mutex::new(None);
+}
 
-    for key in pressed.iter() {
-        match key.as_str() {
-            "CTRL" => parts.push("Ctrl"),
-            "SHIFT" => parts.push("Shift"),
-            "ALT" => parts.push("Alt"),
-            "META" => parts.push("Super"),
-            k => main_key = Some(k),
-        }
+fn triggers_suspended() -> bool {
+    if !TRIGGERS_SUSPENDED.load(Ordering::SeqCst) {
+        return false;
     }
+    let expired = TRIGGERS_RESUME_AT
+        .lock()
+        .map(|resume_at| resume_at.map(|t| std::time::Instant::now() >= t).unwrap_or(false))
+        .unwrap_or(false);
+    if expired {
+        TRIGGERS_SUSPENDED.store(false, Ordering::SeqCst);
+    }
+    !expired
+}
 
-    // If we have a main key (non-modifier), check for match
-    if let Some(key) = main_key {
-        // Sort modifiers for consistent matching
-        parts.sort();
-        parts.push(key);
-        let current_combo = parts.join("+");
-
-        // Check against registered keybinds
-        for (keybind, action) in registry.iter() {
-            // Normalize the registered keybind for comparison
-            let normalized = normalize_keybind(keybind);
-            if normalized == current_combo {
-                if action == "STOP_ALL" {
-                    STOP_ALL_FLAG.store(true, Ordering::SeqCst);
-                } else {
-                    // Play sound by ID
-                    let sound_id = action.clone();
-                    std::thread::spawn(move || {
-                        play_sound_by_id(sound_id);
-                    });
-                }
-                break;
-            }
+// Check if current pressed keys match a registered keybind
+fn check_keybind_match() {
+    if let Some(action) = held_combo_action() {
+        fire_keybind_action(&action);
+    }
+}
+
+// Called before a released key leaves PRESSED_KEYS, so the combo being let go still matches.
+// Only sounds fire on release; whether this one wants to is checked in trigger_sound.
+fn check_release_match() {
+    let Some(action) = held_combo_action() else {
+        return;
+    };
+    if reserved_action_name(&action).is_none() && !is_group_action(&action) {
+        std::thread::spawn(move || trigger_sound(action, true));
+    }
+}
+
+// Action bound to the keys currently held, if any may fire
+fn held_combo_action() -> Option<String> {
+    // Only the panic bind still works while triggers are suspended
+    let suspended = triggers_suspended();
+
+    let pressed = PRESSED_KEYS.lock().unwrap();
+    let registry = KEYBIND_REGISTRY.lock().unwrap();
+    let layout_chars = LAYOUT_CHARS.lock().unwrap();
+    let mapping = if LAYOUT_KEY_MAPPING.load(Ordering::SeqCst) {
+        KeyMapping::Layout
+    } else {
+        KeyMapping::Physical
+    };
+    let combo = pressed_combo(&pressed, &layout_chars, mapping)?;
+    resolve_keybind(&registry, &combo, suspended).or_else(|| {
+        // Nothing bound to the key as reported, try it as the numpad key it may really be
+        NORMALIZE_LOCK_KEYS
+            .load(Ordering::SeqCst)
+            .then(|| numlock_off_combo(&combo))
+            .flatten()
+            .and_then(|combo| resolve_keybind(&registry, &combo, suspended))
+    })
+}
+
+// With NumLock off the numpad reports navigation keys instead of digits
+fn numpad_token_for(key: &str) -> Option<&'static str> {
+    match key {
+        "INSERT" => Some("NUMPAD0"),
+        "END" => Some("NUMPAD1"),
+        "ARROWDOWN" => Some("NUMPAD2"),
+        "PAGEDOWN" => Some("NUMPAD3"),
+        "ARROWLEFT" => Some("NUMPAD4"),
+        "CLEAR" => Some("NUMPAD5"),
+        "ARROWRIGHT" => Some("NUMPAD6"),
+        "HOME" => Some("NUMPAD7"),
+        "ARROWUP" => Some("NUMPAD8"),
+        "PAGEUP" => Some("NUMPAD9"),
+        _ => None,
+    }
+}
+
+// The combo with its main key swapped for the numpad digit, if it's one of those keys.
+// rdev can't tell the numpad from the dedicated keys, so this is only tried as a fallback.
+fn numlock_off_combo(combo: &str) -> Option<String> {
+    let (modifiers, key) = match combo.rsplit_once('+') {
+        Some((modifiers, key)) => (Some(modifiers), key),
+        None => (None, combo),
+    };
+    let numpad = numpad_token_for(key)?;
+    Some(match modifiers {
+        Some(modifiers) => format!("{}+{}", modifiers, numpad),
+        None => numpad.to_string(),
+    })
+}
+
+// Whether CapsLock is toggled on. Unknown (false) where it can't be read.
+#[cfg(windows)]
+fn caps_lock_on() -> bool {
+    #[link(name = "user32")]
+    extern "system" {
+        fn GetKeyState(virtual_key: i32) -> i16;
+    }
+    const VK_CAPITAL: i32 = 0x14;
+    unsafe { GetKeyState(VK_CAPITAL) & 1 != 0 }
+}
+
+#[cfg(not(windows))]
+fn caps_lock_on() -> bool {
+    false
+}
+
+// Build the current keybind string from pressed keys, None until a non-modifier is down
+fn pressed_combo(pressed: &HashSet<String>, layout_chars: &HashMap<String, String>, mapping: KeyMapping) -> Option<String> {
+    let mut parts: Vec<&str> = Vec::new();
+    let mut main_key: Option<&str> = None;
+
+    for key in pressed.iter() {
+        match key.as_str() {
+            "CTRL" => parts.push("Ctrl"),
+            "SHIFT" => parts.push("Shift"),
+            "ALT" => parts.push("Alt"),
+            "META" => parts.push("Super"),
+            k => main_key = Some(translate_key(k, layout_chars, mapping)),
+        }
+    }
+
+    // Sort modifiers for consistent matching
+    parts.sort();
+    parts.push(main_key?);
+    Some(parts.join("+"))
+}
+
+// Action registered for a combo (sound id or sentinel), if it's allowed to fire right now
+fn resolve_keybind(registry: &HashMap<String, String>, combo: &str, suspended: bool) -> Option<String> {
+    for (keybind, action) in registry.iter() {
+        // Normalize the registered keybind for comparison
+        let normalized = normalize_keybind(keybind);
+        if normalized != combo {
+            continue;
+        }
+        if action == "PANIC" {
+            return Some(action.clone());
+        }
+        if !suspended && !blocked_by_modifier_rule(&normalized) {
+            return Some(action.clone());
+        }
+    }
+    None
+}
+
+fn fire_keybind_action(action: &str) {
+    match action {
+        "PANIC" => stop_all_playback(StopAllSource::Panic),
+        "STOP_ALL" => stop_all_playback(StopAllSource::Rdev),
+        "MUTE" | "QUICK_SEARCH" => trigger_control(action),
+        _ if is_group_action(action) => trigger_control(action),
+        _ => {
+            // Play sound by ID
+            let sound_id = action.to_string();
+            std::thread::spawn(move || {
+                play_sound_by_id(sound_id);
+            });
+        }
+    }
+}
+
+// Push a keybind through the same matching a real key press takes and fire what it resolves to.
+// Nothing is sent to the OS (unlike rdev::simulate). Returns the matched sound id or sentinel.
+#[tauri::command]
+fn simulate_keybind(keybind: String) -> Result<Option<String>, String> {
+    let keybind = validate_keybind(&keybind)?;
+    let mut parts: Vec<&str> = keybind.split('+').collect();
+    let main_key = parts.pop().unwrap_or_default();
+    let pressed: HashSet<String> = parts
+        .iter()
+        .map(|m| match *m {
+            "Ctrl" => "CTRL",
+            "Shift" => "SHIFT",
+            "Alt" => "ALT",
+            _ => "META",
+        })
+        .chain(std::iter::once(main_key))
+        .map(str::to_string)
+        .collect();
+
+    // The keybind already holds the token stored in the registry, so it isn't run through the layout again
+    let combo = pressed_combo(&pressed, &HashMap::new(), KeyMapping::Physical)
+        .ok_or_else(|| format!("Keybind '{}' has no key", keybind))?;
+    let action = {
+        let registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        resolve_keybind(&registry, &combo, triggers_suspended())
+    };
+    if let Some(ref action) = action {
+        fire_keybind_action(action);
+    }
+    Ok(action)
+}
+
+// Bare single-key binds would fire on every keystroke while typing in a MotoBoard field
+fn suppressed_while_typing(keybind: &str) -> bool {
+    IGNORE_WHEN_TYPING.load(Ordering::SeqCst)
+        && TEXT_INPUT_FOCUSED.load(Ordering::SeqCst)
+        && !keybind.contains('+')
+}
+
+// A single letter or digit with no modifier
+fn is_bare_alnum(keybind: &str) -> bool {
+    let mut chars = keybind.trim().chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if c.is_ascii_alphanumeric())
+}
+
+fn blocked_by_modifier_rule(keybind: &str) -> bool {
+    REQUIRE_MODIFIER_FOR_ALNUM.load(Ordering::SeqCst) && is_bare_alnum(keybind)
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+struct ForegroundWindow {
+    title: String,
+    // Executable file name, e.g. "game.exe"
+    process: String,
+}
+
+#[cfg(windows)]
+lazy_static::lazy_static! {
+    // Last foreground window handle and its process name, so a key press only pays for
+    // opening the process when focus moves to another window. Titles change (browser tabs,
+    // editors), so they're read on every press.
+    static ref FOREGROUND_CACHE: Mutex<Option<(isize, String)>> = Mutex::new(None);
+}
+
+#[cfg(windows)]
+fn foreground_window() -> Option<ForegroundWindow> {
+    #[link(name = "user32")]
+    extern "system" {
+        fn GetForegroundWindow() -> isize;
+        fn GetWindowTextW(hwnd: isize, text: *mut u16, max_count: i32) -> i32;
+        fn GetWindowThreadProcessId(hwnd: isize, process_id: *mut u32) -> u32;
+    }
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn OpenProcess(access: u32, inherit_handle: i32, process_id: u32) -> isize;
+        fn QueryFullProcessImageNameW(process: isize, flags: u32, name: *mut u16, size: *mut u32) -> i32;
+        fn CloseHandle(handle: isize) -> i32;
+    }
+    const PROCESS_QUERY_LIMITED_INFORMATION: u32 = 0x1000;
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd == 0 {
+        return None;
+    }
+    let mut title = [0u16; 512];
+    let title_len = unsafe { GetWindowTextW(hwnd, title.as_mut_ptr(), title.len() as i32) };
+    let title = String::from_utf16_lossy(&title[..title_len.max(0) as usize]);
+
+    let mut cache = FOREGROUND_CACHE.lock().ok()?;
+    if let Some((cached, ref process)) = *cache {
+        if cached == hwnd {
+            return Some(ForegroundWindow { title, process: process.clone() });
+        }
+    }
+
+    let mut process_id = 0u32;
+    unsafe { GetWindowThreadProcessId(hwnd, &mut process_id) };
+
+    let mut process = String::new();
+    let handle = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, process_id) };
+    if handle != 0 {
+        let mut path = [0u16; 1024];
+        let mut size = path.len() as u32;
+        if unsafe { QueryFullProcessImageNameW(handle, 0, path.as_mut_ptr(), &mut size) } != 0 {
+            let path = String::from_utf16_lossy(&path[..size as usize]);
+            process = path.rsplit(['\\', '/']).next().unwrap_or_default().to_string();
         }
+        unsafe { CloseHandle(handle) };
+    }
+
+    *cache = Some((hwnd, process.clone()));
+    Some(ForegroundWindow { title, process })
+}
+
+#[cfg(not(windows))]
+fn foreground_window() -> Option<ForegroundWindow> {
+    None
+}
+
+// Whether a sound's window filter lets it fire right now. Passes when the foreground
+// window can't be read, so a platform without support never silences binds.
+fn window_filter_allows(filter: Option<&str>) -> bool {
+    let Some(filter) = filter.map(str::to_lowercase) else {
+        return true;
+    };
+    foreground_window().is_none_or(|window| {
+        window.title.to_lowercase().contains(&filter) || window.process.to_lowercase().contains(&filter)
+    })
+}
+
+// Map a physical key token to the token used for matching under the given mode
+fn translate_key<'a>(physical: &'a str, layout_chars: &'a HashMap<String, String>, mapping: KeyMapping) -> &'a str {
+    match mapping {
+        KeyMapping::Physical => physical,
+        KeyMapping::Layout => layout_chars.get(physical).map_or(physical, |c| c.as_str()),
+    }
+}
+
+// Character token for a key press, ignoring control characters produced while Ctrl is held
+fn layout_char(name: Option<&str>) -> Option<String> {
+    let mut chars = name?.chars();
+    let c = chars.next()?;
+    if chars.next().is_some() || !(c.is_alphanumeric() || c.is_ascii_punctuation()) {
+        return None;
     }
+    Some(c.to_uppercase().to_string())
 }
 
 // Normalize keybind string for comparison
@@ -202,6 +1220,25 @@ fn normalize_keybind(keybind: &str) -> String {
     parts.join("+")
 }
 
+// Decoded durations keyed by file path, invalidated when the file's mtime changes
+lazy_static::lazy_static! {
+    static ref DURATION_CACHE: Mutex<HashMap<String, (Option<std::time::SystemTime>, f64)>> = Mutex::new(HashMap::new());
+}
+
+// File metadata for the info tooltip, keyed by path and invalidated on mtime change
+lazy_static::lazy_static! {
+    static ref SOUND_INFO_CACHE: Mutex<HashMap<String, (Option<std::time::SystemTime>, SoundInfo)>> = Mutex::new(HashMap::new());
+}
+
+// Last play notification per sound, plus the last one overall, for rate limiting
+lazy_static::lazy_static! {
+    static ref LAST_NOTIFICATIONS: Mutex<HashMap<String, std::time::Instant>> = Mutex::new(HashMap::new());
+    static ref LAST_ANY_NOTIFICATION: Mutex<Option<std::time::Instant>> = Mutex::new(None);
+}
+
+// Whether the rdev listener thread is up, for diagnostics
+static LISTENER_RUNNING: AtomicBool = AtomicBool::new(false);
+
 // Track last detected key for debugging
 lazy_static::lazy_static! {
     static ref LAST_KEY_PRESS: Mutex<Option<String>> = Mutex::new(None);
@@ -210,25 +1247,40 @@ lazy_static::lazy_static! {
 // Start the low-level keyboard listener in a background thread
 fn start_keyboard_listener() {
     std::thread::spawn(move || {
+        LISTENER_RUNNING.store(true, Ordering::SeqCst);
         if let Err(error) = listen(move |event: Event| {
             match event.event_type {
                 EventType::KeyPress(key) => {
                     if let Some(key_str) = key_to_string(key) {
+                        // CapsLock changes the character on some layouts (AZERTY digits), so keep
+                        // the mapping learned without it
+                        let caps_lock_skews = NORMALIZE_LOCK_KEYS.load(Ordering::SeqCst) && caps_lock_on();
+                        if let Some(c) = layout_char(event.name.as_deref()).filter(|_| !caps_lock_skews) {
+                            if let Ok(mut layout_chars) = LAYOUT_CHARS.lock() {
+                                layout_chars.insert(key_str.clone(), c);
+                            }
+                        }
+
                         // Track last key for debugging
                         if let Ok(mut last) = LAST_KEY_PRESS.lock() {
                             *last = Some(key_str.clone());
                         }
 
+                        LAST_KEY_EVENT_MS.store(unix_now_millis(), Ordering::SeqCst);
                         let mut pressed = PRESSED_KEYS.lock().unwrap();
-                        pressed.insert(key_str);
+                        let is_repeat = !pressed.insert(key_str);
                         drop(pressed);
 
-                        // Always check for match on every key press
-                        check_keybind_match();
+                        // A key already held is an auto-repeat, only re-fire if repeats are wanted
+                        if !is_repeat || !IGNORE_KEY_REPEAT.load(Ordering::SeqCst) {
+                            check_keybind_match();
+                        }
                     }
                 }
                 EventType::KeyRelease(key) => {
                     if let Some(key_str) = key_to_string(key) {
+                        LAST_KEY_EVENT_MS.store(unix_now_millis(), Ordering::SeqCst);
+                        check_release_match();
                         let mut pressed = PRESSED_KEYS.lock().unwrap();
                         pressed.remove(&key_str);
                     }
@@ -238,12 +1290,32 @@ fn start_keyboard_listener() {
         }) {
             eprintln!("Keyboard listener error: {:?}", error);
         }
+        LISTENER_RUNNING.store(false, Ordering::SeqCst);
+    });
+}
+
+fn clear_pressed_keys() {
+    if let Ok(mut pressed) = PRESSED_KEYS.lock() {
+        pressed.clear();
+    }
+}
+
+// Drop held keys once the keyboard has been idle longer than the stale-key timeout
+fn start_pressed_key_sweeper() {
+    std::thread::spawn(|| loop {
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        let timeout = STALE_KEY_TIMEOUT_MS.load(Ordering::SeqCst);
+        let idle = unix_now_millis().saturating_sub(LAST_KEY_EVENT_MS.load(Ordering::SeqCst));
+        if timeout > 0 && idle >= timeout {
+            clear_pressed_keys();
+        }
     });
 }
 
-// Check if we should persist data (only in release builds)
+// Check if we should persist data (only in release builds, never in safe mode)
 fn should_persist() -> bool {
-    !cfg!(debug_assertions)
+    !cfg!(debug_assertions) && !SAFE_MODE.load(Ordering::SeqCst)
 }
 
 // Get the config directory for saving data
@@ -260,6 +1332,36 @@ fn ensure_config_dir() -> Option<PathBuf> {
     Some(config_dir)
 }
 
+// Managed sound library: audio files copied into the config dir so a board is self-contained
+fn get_library_dir() -> Option<PathBuf> {
+    get_config_dir().map(|p| p.join("sounds"))
+}
+
+fn ensure_library_dir() -> Option<PathBuf> {
+    let library_dir = get_library_dir()?;
+    if !library_dir.exists() {
+        std::fs::create_dir_all(&library_dir).ok()?;
+    }
+    Some(library_dir)
+}
+
+fn is_in_library(file_path: &str) -> bool {
+    get_library_dir()
+        .map(|dir| PathBuf::from(file_path).starts_with(dir))
+        .unwrap_or(false)
+}
+
+// Copy an audio file into the library, named after the sound id to avoid collisions
+fn copy_into_library(source: &std::path::Path, sound_id: &str) -> Result<PathBuf, String> {
+    let library_dir = ensure_library_dir().ok_or_else(|| "Could not create sound library".to_string())?;
+    let mut dest = library_dir.join(sound_id);
+    if let Some(ext) = source.extension() {
+        dest.set_extension(ext);
+    }
+    std::fs::copy(source, &dest).map_err(|e| format!("Failed to copy file: {}", e))?;
+    Ok(dest)
+}
+
 // Save sounds to file
 fn save_sounds(sounds: &HashMap<String, Sound>) {
     if !should_persist() {
@@ -269,14 +1371,92 @@ fn save_sounds(sounds: &HashMap<String, Sound>) {
     if let Some(config_dir) = ensure_config_dir() {
         let sounds_file = config_dir.join("sounds.json");
         let sounds_vec: Vec<&Sound> = sounds.values().collect();
-        if let Ok(json) = serde_json::to_string_pretty(&sounds_vec) {
-            if let Ok(mut file) = File::create(&sounds_file) {
-                let _ = file.write_all(json.as_bytes());
-            }
+        let written = serde_json::to_string_pretty(&sounds_vec)
+            .is_ok_and(|json| std::fs::write(&sounds_file, json).is_ok());
+        if !written {
+            return;
+        }
+
+        // sounds.json stays a bare list so older builds can still read it; its stamp lives next to
+        // it and is only updated once the list itself was written
+        let stamp = DataStamp {
+            schema_version: Some(DATA_SCHEMA_VERSION),
+            written_by: Some(VERSION.to_string()),
+        };
+        if let Ok(json) = serde_json::to_string_pretty(&stamp) {
+            let _ = std::fs::write(config_dir.join(SOUNDS_STAMP_FILE), json);
+        }
+    }
+}
+
+const SOUNDS_STAMP_FILE: &str = "sounds.meta.json";
+
+// Schema version and app version that last wrote a data file
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct DataStamp {
+    #[serde(rename = "schemaVersion", default)]
+    schema_version: Option<u32>,
+    #[serde(rename = "writtenBy", default)]
+    written_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+struct FileVersion {
+    // False when there was no file to load
+    found: bool,
+    // None for files written before stamping existed
+    #[serde(rename = "schemaVersion")]
+    schema_version: Option<u32>,
+    #[serde(rename = "writtenBy")]
+    written_by: Option<String>,
+    // Written by a newer build; fields this one doesn't know were dropped on load
+    #[serde(rename = "newerThanApp")]
+    newer_than_app: bool,
+    // The loader converted an older layout, rewritten in the current one on the next save.
+    // Every schema so far reads as-is, so nothing sets this yet.
+    migrated: bool,
+}
+
+impl FileVersion {
+    fn loaded(stamp: DataStamp) -> Self {
+        let version = stamp.schema_version.unwrap_or(0);
+        Self {
+            found: true,
+            schema_version: stamp.schema_version,
+            written_by: stamp.written_by,
+            newer_than_app: version > DATA_SCHEMA_VERSION,
+            migrated: false,
         }
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct DataVersions {
+    #[serde(rename = "appVersion")]
+    app_version: String,
+    #[serde(rename = "appSchemaVersion")]
+    app_schema_version: u32,
+    sounds: FileVersion,
+    settings: FileVersion,
+}
+
+// What the last load found in sounds.json and settings.json
+lazy_static::lazy_static! {
+    static ref LOADED_VERSIONS: Mutex<(FileVersion, FileVersion)> = Mutex::new(Default::default());
+}
+
+// Versions of the data files as last loaded, so the UI can warn about files from a newer build
+#[tauri::command]
+fn get_data_versions() -> Result<DataVersions, String> {
+    let (sounds, settings) = LOADED_VERSIONS.lock().map_err(|e| e.to_string())?.clone();
+    Ok(DataVersions {
+        app_version: VERSION.to_string(),
+        app_schema_version: DATA_SCHEMA_VERSION,
+        sounds,
+        settings,
+    })
+}
+
 // Load sounds from file
 fn load_sounds() -> HashMap<String, Sound> {
     if !should_persist() {
@@ -288,6 +1468,13 @@ fn load_sounds() -> HashMap<String, Sound> {
         if sounds_file.exists() {
             if let Ok(file) = File::open(&sounds_file) {
                 if let Ok(sounds_vec) = serde_json::from_reader::<_, Vec<Sound>>(BufReader::new(file)) {
+                    let stamp = File::open(config_dir.join(SOUNDS_STAMP_FILE))
+                        .ok()
+                        .and_then(|file| serde_json::from_reader::<_, DataStamp>(BufReader::new(file)).ok())
+                        .unwrap_or_default();
+                    if let Ok(mut versions) = LOADED_VERSIONS.lock() {
+                        versions.0 = FileVersion::loaded(stamp);
+                    }
                     return sounds_vec.into_iter().map(|s| (s.id.clone(), s)).collect();
                 }
             }
@@ -296,118 +1483,586 @@ fn load_sounds() -> HashMap<String, Sound> {
     HashMap::new()
 }
 
-// Persistent settings structure (includes UI settings)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
-struct PersistentSettings {
-    #[serde(rename = "primaryDevice")]
-    primary_device: Option<String>,
-    #[serde(rename = "monitorDevice")]
-    monitor_device: Option<String>,
-    #[serde(rename = "masterVolume", default = "default_volume")]
-    master_volume: f32,
-    #[serde(rename = "stopAllKeybind")]
-    stop_all_keybind: Option<String>,
-    #[serde(rename = "compactMode", default)]
-    compact_mode: bool,
-    #[serde(default = "default_theme")]
-    theme: String,
-    #[serde(rename = "minimizeToTray", default)]
-    minimize_to_tray: bool,
-    #[serde(rename = "overlapMode", default = "default_overlap")]
-    overlap_mode: bool,
-    #[serde(rename = "crossfadeDuration", default)]
-    crossfade_duration: u32,
+struct SoundStats {
+    #[serde(rename = "sessionCount", default)]
+    session_count: u64,
+    #[serde(rename = "allTimeCount", default)]
+    all_time_count: u64,
+    #[serde(rename = "lastPlayed", default)]
+    last_played: Option<u64>,
 }
 
-fn default_volume() -> f32 {
-    0.8
+// Play counts, kept in their own file so a play doesn't rewrite sounds.json
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct PlayStats {
+    #[serde(default)]
+    sounds: HashMap<String, SoundStats>,
+    // Unix seconds of the last session reset
+    #[serde(rename = "lastReset", default)]
+    last_reset: Option<u64>,
 }
 
-fn default_overlap() -> bool {
-    true
+fn unix_now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
 }
 
-fn default_theme() -> String {
-    "green".to_string()
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
 }
 
-// Save settings to file
-fn save_settings(state: &AudioState) {
+impl PlayStats {
+    fn record_play(&mut self, sound_id: &str) {
+        let stats = self.sounds.entry(sound_id.to_string()).or_default();
+        stats.session_count += 1;
+        stats.all_time_count += 1;
+        stats.last_played = Some(unix_now());
+    }
+
+    // Clear session counts, all-time counts are kept
+    fn reset_session(&mut self) {
+        for stats in self.sounds.values_mut() {
+            stats.session_count = 0;
+        }
+        self.last_reset = Some(unix_now());
+    }
+
+    // Whether the policy calls for a reset right now. Days roll over at midnight UTC.
+    fn reset_due(&self, period: StatsResetPeriod) -> bool {
+        match period {
+            StatsResetPeriod::Daily => {
+                self.last_reset.is_none_or(|last| last / 86400 != unix_now() / 86400)
+            }
+            StatsResetPeriod::Never | StatsResetPeriod::OnAppStart => false,
+        }
+    }
+}
+
+fn save_stats(stats: &PlayStats) {
     if !should_persist() {
         return;
     }
 
     if let Some(config_dir) = ensure_config_dir() {
-        let settings_file = config_dir.join("settings.json");
-        let settings = PersistentSettings {
-            primary_device: state.primary_device.clone(),
-            monitor_device: state.monitor_device.clone(),
-            master_volume: state.master_volume,
-            stop_all_keybind: state.stop_all_keybind.clone(),
-            compact_mode: state.compact_mode,
-            theme: state.theme.clone(),
-            minimize_to_tray: state.minimize_to_tray,
-            overlap_mode: state.overlap_mode,
-            crossfade_duration: state.crossfade_duration,
-        };
-        if let Ok(json) = serde_json::to_string_pretty(&settings) {
-            if let Ok(mut file) = File::create(&settings_file) {
+        let stats_file = config_dir.join("stats.json");
+        if let Ok(json) = serde_json::to_string_pretty(stats) {
+            if let Ok(mut file) = File::create(&stats_file) {
                 let _ = file.write_all(json.as_bytes());
             }
         }
     }
 }
 
-// Load settings from file
-fn load_settings() -> Option<PersistentSettings> {
+fn load_stats() -> PlayStats {
     if !should_persist() {
-        return None;
+        return PlayStats::default();
     }
 
     if let Some(config_dir) = get_config_dir() {
-        let settings_file = config_dir.join("settings.json");
-        if settings_file.exists() {
-            if let Ok(file) = File::open(&settings_file) {
-                if let Ok(settings) = serde_json::from_reader::<_, PersistentSettings>(BufReader::new(file)) {
-                    return Some(settings);
-                }
+        let stats_file = config_dir.join("stats.json");
+        if let Ok(file) = File::open(&stats_file) {
+            if let Ok(stats) = serde_json::from_reader::<_, PlayStats>(BufReader::new(file)) {
+                return stats;
             }
         }
     }
-    None
+    PlayStats::default()
 }
 
+// A sound that was playing at shutdown, kept in playing.json apart from the settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct Sound {
-    id: String,
-    name: String,
-    keybind: Option<String>,
-    volume: f32,
-    #[serde(rename = "filePath")]
-    file_path: String,
-    #[serde(rename = "startTime")]
-    start_time: Option<f64>,
-    #[serde(rename = "endTime")]
-    end_time: Option<f64>,
-    #[serde(default)]
-    order: i32,
-    #[serde(rename = "loopMode", default)]
-    loop_mode: bool,
-    #[serde(rename = "playbackSpeed", default = "default_speed")]
-    playback_speed: f32,
-    #[serde(rename = "echoDelay", default)]
-    echo_delay: f32,
-    #[serde(rename = "echoVolume", default)]
-    echo_volume: f32,
-    #[serde(rename = "reverbDecay", default)]
-    reverb_decay: f32,
-    #[serde(rename = "bassBoost", default)]
-    bass_boost: f32,
-    #[serde(rename = "fakeBassBoost", default)]
-    fake_bass_boost: f32,
+struct ResumeEntry {
+    #[serde(rename = "soundId")]
+    sound_id: String,
+    looping: bool,
 }
 
-fn default_speed() -> f32 {
+fn save_resume_playback(entries: &[ResumeEntry]) {
+    if !should_persist() {
+        return;
+    }
+
+    if let Some(config_dir) = ensure_config_dir() {
+        let playing_file = config_dir.join("playing.json");
+        if let Ok(json) = serde_json::to_string_pretty(entries) {
+            if let Ok(mut file) = File::create(&playing_file) {
+                let _ = file.write_all(json.as_bytes());
+            }
+        }
+    }
+}
+
+// Read and remove the list, so one crash-looping sound can't come back every launch
+fn take_resume_playback() -> Vec<ResumeEntry> {
+    if !should_persist() {
+        return Vec::new();
+    }
+
+    let Some(playing_file) = get_config_dir().map(|dir| dir.join("playing.json")) else {
+        return Vec::new();
+    };
+    let entries = File::open(&playing_file)
+        .ok()
+        .and_then(|file| serde_json::from_reader(BufReader::new(file)).ok())
+        .unwrap_or_default();
+    let _ = std::fs::remove_file(&playing_file);
+    entries
+}
+
+// Restart what was playing when the app last closed, if the user asked for it
+fn restore_playback(app_handle: &AppHandle) {
+    let entries = take_resume_playback();
+    let state: State<AppState> = app_handle.state();
+    let mode = match state.lock() {
+        Ok(audio_state) if audio_state.restore_playback_on_start => audio_state.restore_playback_mode,
+        _ => return,
+    };
+
+    let engine: State<Engine> = app_handle.state();
+    for entry in entries {
+        if mode == RestorePlaybackMode::LoopsOnly && !entry.looping {
+            continue;
+        }
+        if let Err(e) = start_sound(entry.sound_id.clone(), None, false, &state, &engine) {
+            eprintln!("[Restore] Couldn't resume {}: {}", entry.sound_id, e);
+        }
+    }
+}
+
+#[tauri::command]
+fn set_restore_playback_on_start(
+    enabled: bool,
+    mode: Option<RestorePlaybackMode>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.restore_playback_on_start = enabled;
+    if let Some(mode) = mode {
+        audio_state.restore_playback_mode = mode;
+    }
+    save_settings(&audio_state);
+    Ok(())
+}
+
+fn emit_stats_reset() {
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let _ = app_handle.emit_all("stats-reset", ());
+    }
+}
+
+// Apply the daily policy once a minute so a long-running session still rolls over
+fn start_stats_reset_timer() {
+    std::thread::spawn(|| loop {
+        std::thread::sleep(std::time::Duration::from_secs(60));
+
+        let Some(app_handle) = APP_HANDLE.get() else {
+            continue;
+        };
+        let state: State<AppState> = app_handle.state();
+        let reset = match state.lock() {
+            Ok(mut audio_state) => {
+                if audio_state.stats.reset_due(audio_state.stats_reset) {
+                    audio_state.stats.reset_session();
+                    save_stats(&audio_state.stats);
+                    true
+                } else {
+                    false
+                }
+            }
+            Err(_) => false,
+        };
+        if reset {
+            emit_stats_reset();
+        }
+    });
+}
+
+// Persistent settings structure (includes UI settings)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct PersistentSettings {
+    #[serde(rename = "primaryDevice")]
+    primary_device: Option<String>,
+    #[serde(rename = "monitorDevice")]
+    monitor_device: Option<String>,
+    #[serde(rename = "masterVolume", default = "default_volume")]
+    master_volume: f32,
+    #[serde(rename = "stopAllKeybind")]
+    stop_all_keybind: Option<String>,
+    #[serde(rename = "compactMode", default)]
+    compact_mode: bool,
+    #[serde(default = "default_theme")]
+    theme: String,
+    #[serde(rename = "minimizeToTray", default)]
+    minimize_to_tray: bool,
+    #[serde(rename = "overlapMode", default = "default_overlap")]
+    overlap_mode: bool,
+    #[serde(rename = "crossfadeDuration", default)]
+    crossfade_duration: u32,
+    #[serde(rename = "extraDevices", default)]
+    extra_devices: Vec<String>,
+    #[serde(rename = "playbackThreads", default = "default_playback_threads")]
+    playback_threads: usize,
+    #[serde(rename = "maxConcurrentSounds", default)]
+    max_concurrent_sounds: usize,
+    #[serde(rename = "concurrencyPolicy", default)]
+    concurrency_policy: ConcurrencyPolicy,
+    #[serde(rename = "groupOrder", default)]
+    group_order: Vec<String>,
+    #[serde(rename = "statsReset", default)]
+    stats_reset: StatsResetPeriod,
+    #[serde(rename = "updateChannel", default = "default_update_channel")]
+    update_channel: String,
+    #[serde(rename = "autoCheckUpdates", default)]
+    auto_check_updates: bool,
+    #[serde(rename = "lastUpdateCheck", default)]
+    last_update_check: Option<u64>,
+    #[serde(rename = "skippedUpdates", default)]
+    skipped_updates: Vec<String>,
+    #[serde(rename = "keyMapping", default)]
+    key_mapping: KeyMapping,
+    #[serde(rename = "gridColumns", default = "default_grid_columns")]
+    grid_columns: u32,
+    #[serde(rename = "ignoreKeyRepeat", default = "default_ignore_key_repeat")]
+    ignore_key_repeat: bool,
+    #[serde(rename = "muteKeybind", default)]
+    mute_keybind: Option<String>,
+    #[serde(rename = "trayLeftClickAction", default)]
+    tray_left_click_action: TrayClickAction,
+    #[serde(rename = "alsoPlayDefault", default)]
+    also_play_default: bool,
+    #[serde(rename = "ignoreWhenTyping", default)]
+    ignore_when_typing: bool,
+    #[serde(rename = "requireModifierForAlnum", default)]
+    require_modifier_for_alnum: bool,
+    #[serde(rename = "inputDevice", default)]
+    input_device: Option<String>,
+    #[serde(rename = "quickSearchKeybind", default)]
+    quick_search_keybind: Option<String>,
+    #[serde(rename = "deviceGain", default)]
+    device_gain: HashMap<String, f32>,
+    #[serde(rename = "defaultSoundVolume", default = "default_sound_volume")]
+    default_sound_volume: f32,
+    #[serde(rename = "outputSyncMs", default)]
+    output_sync_ms: u32,
+    #[serde(rename = "missingDeviceBehavior", default)]
+    missing_device_behavior: MissingDeviceBehavior,
+    #[serde(rename = "groupStepKeybinds", default)]
+    group_step_keybinds: HashMap<String, String>,
+    #[serde(rename = "fileOpenTimeoutMs", default = "default_file_open_timeout_ms")]
+    file_open_timeout_ms: u32,
+    #[serde(rename = "compactHideUnbound", default)]
+    compact_hide_unbound: bool,
+    #[serde(rename = "deviceConfigs", default)]
+    device_configs: HashMap<String, DeviceConfig>,
+    #[serde(rename = "staleKeyTimeoutMs", default = "default_stale_key_timeout_ms")]
+    stale_key_timeout_ms: u32,
+    #[serde(rename = "lastImportDir", default)]
+    last_import_dir: Option<String>,
+    #[serde(rename = "maxPlaySeconds", default)]
+    max_play_seconds: Option<u64>,
+    #[serde(rename = "groupShowKeybinds", default)]
+    group_show_keybinds: HashMap<String, String>,
+    #[serde(rename = "monitorDownmixMono", default)]
+    monitor_downmix_mono: bool,
+    #[serde(rename = "restorePlaybackOnStart", default)]
+    restore_playback_on_start: bool,
+    #[serde(rename = "restorePlaybackMode", default)]
+    restore_playback_mode: RestorePlaybackMode,
+    #[serde(rename = "normalizeLockKeys", default = "default_normalize_lock_keys")]
+    normalize_lock_keys: bool,
+    #[serde(rename = "prebufferMs", default)]
+    prebuffer_ms: u32,
+    // Stamped on save, see get_data_versions
+    #[serde(rename = "schemaVersion", default)]
+    schema_version: Option<u32>,
+    #[serde(rename = "writtenBy", default)]
+    written_by: Option<String>,
+}
+
+fn default_playback_threads() -> usize {
+    DEFAULT_PLAYBACK_THREADS
+}
+
+const UPDATE_CHANNELS: [&str; 2] = ["stable", "beta"];
+
+fn default_update_channel() -> String {
+    "stable".to_string()
+}
+
+// Fall back to stable for anything that isn't a known channel
+fn normalize_update_channel(channel: &str) -> String {
+    let channel = channel.trim().to_lowercase();
+    if UPDATE_CHANNELS.contains(&channel.as_str()) {
+        channel
+    } else {
+        default_update_channel()
+    }
+}
+
+fn default_grid_columns() -> u32 {
+    6
+}
+
+fn default_ignore_key_repeat() -> bool {
+    true
+}
+
+fn default_normalize_lock_keys() -> bool {
+    true
+}
+
+fn default_volume() -> f32 {
+    0.8
+}
+
+fn default_sound_volume() -> f32 {
+    1.0
+}
+
+fn default_overlap() -> bool {
+    true
+}
+
+fn default_theme() -> String {
+    "green".to_string()
+}
+
+// Everything in AudioState that is saved to settings.json
+fn persistent_settings(state: &AudioState) -> PersistentSettings {
+    PersistentSettings {
+        primary_device: state.primary_device.clone(),
+        monitor_device: state.monitor_device.clone(),
+        master_volume: state.master_volume,
+        stop_all_keybind: state.stop_all_keybind.clone(),
+        compact_mode: state.compact_mode,
+        theme: state.theme.clone(),
+        minimize_to_tray: state.minimize_to_tray,
+        overlap_mode: state.overlap_mode,
+        crossfade_duration: state.crossfade_duration,
+        extra_devices: state.extra_devices.clone(),
+        playback_threads: state.playback_threads,
+        max_concurrent_sounds: state.max_concurrent_sounds,
+        concurrency_policy: state.concurrency_policy,
+        group_order: state.group_order.clone(),
+        stats_reset: state.stats_reset,
+        update_channel: state.update_channel.clone(),
+        auto_check_updates: state.auto_check_updates,
+        last_update_check: state.last_update_check,
+        skipped_updates: state.skipped_updates.clone(),
+        key_mapping: state.key_mapping,
+        grid_columns: state.grid_columns,
+        ignore_key_repeat: state.ignore_key_repeat,
+        mute_keybind: state.mute_keybind.clone(),
+        tray_left_click_action: state.tray_left_click_action,
+        also_play_default: state.also_play_default,
+        ignore_when_typing: state.ignore_when_typing,
+        require_modifier_for_alnum: state.require_modifier_for_alnum,
+        input_device: state.input_device.clone(),
+        quick_search_keybind: state.quick_search_keybind.clone(),
+        device_gain: state.device_gain.clone(),
+        default_sound_volume: state.default_sound_volume,
+        output_sync_ms: state.output_sync_ms,
+        missing_device_behavior: state.missing_device_behavior,
+        group_step_keybinds: state.group_step_keybinds.clone(),
+        file_open_timeout_ms: state.file_open_timeout_ms,
+        compact_hide_unbound: state.compact_hide_unbound,
+        device_configs: state.device_configs.clone(),
+        stale_key_timeout_ms: state.stale_key_timeout_ms,
+        last_import_dir: state.last_import_dir.clone(),
+        max_play_seconds: state.max_play_seconds,
+        group_show_keybinds: state.group_show_keybinds.clone(),
+        monitor_downmix_mono: state.monitor_downmix_mono,
+        restore_playback_on_start: state.restore_playback_on_start,
+        restore_playback_mode: state.restore_playback_mode,
+        normalize_lock_keys: state.normalize_lock_keys,
+        prebuffer_ms: state.prebuffer_ms,
+        schema_version: Some(DATA_SCHEMA_VERSION),
+        written_by: Some(VERSION.to_string()),
+    }
+}
+
+// Save settings to file
+fn save_settings(state: &AudioState) {
+    if !should_persist() {
+        return;
+    }
+
+    if let Some(config_dir) = ensure_config_dir() {
+        let settings_file = config_dir.join("settings.json");
+        let settings = persistent_settings(state);
+        if let Ok(json) = serde_json::to_string_pretty(&settings) {
+            if let Ok(mut file) = File::create(&settings_file) {
+                let _ = file.write_all(json.as_bytes());
+            }
+        }
+    }
+}
+
+// Load settings from file
+fn load_settings() -> Option<PersistentSettings> {
+    if !should_persist() {
+        return None;
+    }
+
+    if let Some(config_dir) = get_config_dir() {
+        let settings_file = config_dir.join("settings.json");
+        if settings_file.exists() {
+            if let Ok(file) = File::open(&settings_file) {
+                if let Ok(settings) = serde_json::from_reader::<_, PersistentSettings>(BufReader::new(file)) {
+                    if let Ok(mut versions) = LOADED_VERSIONS.lock() {
+                        versions.1 = FileVersion::loaded(DataStamp {
+                            schema_version: settings.schema_version,
+                            written_by: settings.written_by.clone(),
+                        });
+                    }
+                    return Some(settings);
+                }
+            }
+        }
+    }
+    None
+}
+
+// Push settings that are mirrored outside AudioState (atomics read by the keyboard hook,
+// the worker pool, the engine) after they were loaded or replaced
+fn sync_runtime_settings(state: &AudioState, engine: &AudioEngine) {
+    PLAYBACK_POOL.resize(state.playback_threads);
+    LAYOUT_KEY_MAPPING.store(state.key_mapping == KeyMapping::Layout, Ordering::SeqCst);
+    IGNORE_KEY_REPEAT.store(state.ignore_key_repeat, Ordering::SeqCst);
+    NORMALIZE_LOCK_KEYS.store(state.normalize_lock_keys, Ordering::SeqCst);
+    IGNORE_WHEN_TYPING.store(state.ignore_when_typing, Ordering::SeqCst);
+    REQUIRE_MODIFIER_FOR_ALNUM.store(state.require_modifier_for_alnum, Ordering::SeqCst);
+    STALE_KEY_TIMEOUT_MS.store(state.stale_key_timeout_ms as u64, Ordering::SeqCst);
+    if let Ok(mut gains) = engine.device_gains.lock() {
+        *gains = state.device_gain.clone();
+    }
+    if let Ok(mut configs) = engine.device_configs.lock() {
+        *configs = state.device_configs.clone();
+    }
+    engine.output_sync_ms.store(state.output_sync_ms as u64, Ordering::SeqCst);
+    engine.prebuffer_ms.store(state.prebuffer_ms as u64, Ordering::SeqCst);
+    engine.max_play_secs.store(state.max_play_seconds.unwrap_or(0), Ordering::SeqCst);
+    FILE_OPEN_TIMEOUT_MS.store(state.file_open_timeout_ms as u64, Ordering::SeqCst);
+    if let Ok(mut behavior) = engine.missing_device_behavior.lock() {
+        *behavior = state.missing_device_behavior;
+    }
+}
+
+// Load saved settings into the state, clamping values that are validated when set
+fn apply_settings(state: &mut AudioState, settings: PersistentSettings) {
+    state.primary_device = settings.primary_device;
+    state.monitor_device = settings.monitor_device;
+    state.master_volume = settings.master_volume;
+    state.stop_all_keybind = settings.stop_all_keybind;
+    state.compact_mode = settings.compact_mode;
+    state.theme = settings.theme;
+    state.minimize_to_tray = settings.minimize_to_tray;
+    state.overlap_mode = settings.overlap_mode;
+    state.crossfade_duration = settings.crossfade_duration;
+    state.extra_devices = settings.extra_devices;
+    state.playback_threads = settings.playback_threads.clamp(1, 64);
+    state.max_concurrent_sounds = settings.max_concurrent_sounds;
+    state.concurrency_policy = settings.concurrency_policy;
+    state.group_order = settings.group_order;
+    state.stats_reset = settings.stats_reset;
+    state.update_channel = normalize_update_channel(&settings.update_channel);
+    state.auto_check_updates = settings.auto_check_updates;
+    state.last_update_check = settings.last_update_check;
+    state.skipped_updates = settings.skipped_updates;
+    state.key_mapping = settings.key_mapping;
+    state.grid_columns = settings.grid_columns.clamp(1, 12);
+    state.ignore_key_repeat = settings.ignore_key_repeat;
+    state.mute_keybind = settings.mute_keybind;
+    state.tray_left_click_action = settings.tray_left_click_action;
+    state.also_play_default = settings.also_play_default;
+    state.ignore_when_typing = settings.ignore_when_typing;
+    state.require_modifier_for_alnum = settings.require_modifier_for_alnum;
+    state.input_device = settings.input_device;
+    state.quick_search_keybind = settings.quick_search_keybind;
+    state.device_gain = settings.device_gain;
+    state.default_sound_volume = settings.default_sound_volume;
+    state.output_sync_ms = settings.output_sync_ms;
+    state.missing_device_behavior = settings.missing_device_behavior;
+    state.group_step_keybinds = settings.group_step_keybinds;
+    state.file_open_timeout_ms = clamp_file_open_timeout(settings.file_open_timeout_ms);
+    state.compact_hide_unbound = settings.compact_hide_unbound;
+    state.device_configs = settings.device_configs;
+    state.stale_key_timeout_ms = clamp_stale_key_timeout(settings.stale_key_timeout_ms);
+    state.last_import_dir = settings.last_import_dir;
+    state.max_play_seconds = settings.max_play_seconds;
+    state.group_show_keybinds = settings.group_show_keybinds;
+    state.monitor_downmix_mono = settings.monitor_downmix_mono;
+    state.restore_playback_on_start = settings.restore_playback_on_start;
+    state.restore_playback_mode = settings.restore_playback_mode;
+    state.normalize_lock_keys = settings.normalize_lock_keys;
+    state.prebuffer_ms = settings.prebuffer_ms;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Sound {
+    id: String,
+    name: String,
+    keybind: Option<String>,
+    volume: f32,
+    #[serde(rename = "filePath")]
+    file_path: String,
+    #[serde(rename = "startTime")]
+    start_time: Option<f64>,
+    #[serde(rename = "endTime")]
+    end_time: Option<f64>,
+    #[serde(default)]
+    order: i32,
+    #[serde(rename = "loopMode", default)]
+    loop_mode: bool,
+    #[serde(rename = "playbackSpeed", default = "default_speed")]
+    playback_speed: f32,
+    #[serde(rename = "echoDelay", default)]
+    echo_delay: f32,
+    #[serde(rename = "echoVolume", default)]
+    echo_volume: f32,
+    #[serde(rename = "reverbDecay", default)]
+    reverb_decay: f32,
+    #[serde(rename = "bassBoost", default)]
+    bass_boost: f32,
+    #[serde(rename = "fakeBassBoost", default)]
+    fake_bass_boost: f32,
+    #[serde(rename = "exclusiveGroup", default)]
+    exclusive_group: Option<String>,
+    #[serde(rename = "randomStart", default)]
+    random_start: bool,
+    // Frame offsets for sample-accurate trims, override start_time/end_time when set
+    #[serde(rename = "startSample", default)]
+    start_sample: Option<u64>,
+    #[serde(rename = "endSample", default)]
+    end_sample: Option<u64>,
+    #[serde(default)]
+    group: Option<String>,
+    #[serde(rename = "notifyOnPlay", default)]
+    notify_on_play: bool,
+    // Unix millis when the pad was added, 0 for sounds saved before this was tracked
+    #[serde(rename = "createdAt", default)]
+    created_at: u64,
+    // URL that gets a POST each time the sound plays (OBS bridges, stream tools)
+    #[serde(rename = "onPlayWebhook", default)]
+    on_play_webhook: Option<String>,
+    // Pad accent color as a CSS color string
+    #[serde(default)]
+    color: Option<String>,
+    // Keybind only fires while the focused window's title or process contains this
+    #[serde(rename = "activeWindowFilter", default)]
+    active_window_filter: Option<String>,
+    // Fire when the keybind is released instead of pressed (rdev listener only)
+    #[serde(rename = "triggerOnRelease", default)]
+    trigger_on_release: bool,
+}
+
+fn default_speed() -> f32 {
     1.0
 }
 
@@ -430,6 +2085,80 @@ struct Settings {
     overlap_mode: bool,
     #[serde(rename = "crossfadeDuration")]
     crossfade_duration: u32,
+    #[serde(rename = "extraDevices")]
+    extra_devices: Vec<String>,
+    #[serde(rename = "monitorOnly")]
+    monitor_only: bool,
+    #[serde(rename = "playbackThreads")]
+    playback_threads: usize,
+    #[serde(rename = "maxConcurrentSounds")]
+    max_concurrent_sounds: usize,
+    #[serde(rename = "concurrencyPolicy")]
+    concurrency_policy: ConcurrencyPolicy,
+    #[serde(rename = "groupOrder")]
+    group_order: Vec<String>,
+    #[serde(rename = "statsReset")]
+    stats_reset: StatsResetPeriod,
+    #[serde(rename = "updateChannel")]
+    update_channel: String,
+    #[serde(rename = "autoCheckUpdates")]
+    auto_check_updates: bool,
+    #[serde(rename = "skippedUpdates")]
+    skipped_updates: Vec<String>,
+    #[serde(rename = "keyMapping")]
+    key_mapping: KeyMapping,
+    #[serde(rename = "gridColumns")]
+    grid_columns: u32,
+    #[serde(rename = "ignoreKeyRepeat")]
+    ignore_key_repeat: bool,
+    #[serde(rename = "muteKeybind")]
+    mute_keybind: Option<String>,
+    #[serde(rename = "trayLeftClickAction")]
+    tray_left_click_action: TrayClickAction,
+    #[serde(rename = "alsoPlayDefault")]
+    also_play_default: bool,
+    #[serde(rename = "ignoreWhenTyping")]
+    ignore_when_typing: bool,
+    #[serde(rename = "requireModifierForAlnum")]
+    require_modifier_for_alnum: bool,
+    #[serde(rename = "inputDevice")]
+    input_device: Option<String>,
+    #[serde(rename = "quickSearchKeybind")]
+    quick_search_keybind: Option<String>,
+    #[serde(rename = "deviceGain")]
+    device_gain: HashMap<String, f32>,
+    #[serde(rename = "defaultSoundVolume")]
+    default_sound_volume: f32,
+    #[serde(rename = "outputSyncMs")]
+    output_sync_ms: u32,
+    #[serde(rename = "missingDeviceBehavior")]
+    missing_device_behavior: MissingDeviceBehavior,
+    #[serde(rename = "groupStepKeybinds")]
+    group_step_keybinds: HashMap<String, String>,
+    #[serde(rename = "fileOpenTimeoutMs")]
+    file_open_timeout_ms: u32,
+    #[serde(rename = "compactHideUnbound")]
+    compact_hide_unbound: bool,
+    #[serde(rename = "deviceConfigs")]
+    device_configs: HashMap<String, DeviceConfig>,
+    #[serde(rename = "staleKeyTimeoutMs")]
+    stale_key_timeout_ms: u32,
+    #[serde(rename = "lastImportDir")]
+    last_import_dir: Option<String>,
+    #[serde(rename = "maxPlaySeconds")]
+    max_play_seconds: Option<u64>,
+    #[serde(rename = "groupShowKeybinds")]
+    group_show_keybinds: HashMap<String, String>,
+    #[serde(rename = "monitorDownmixMono")]
+    monitor_downmix_mono: bool,
+    #[serde(rename = "restorePlaybackOnStart")]
+    restore_playback_on_start: bool,
+    #[serde(rename = "restorePlaybackMode")]
+    restore_playback_mode: RestorePlaybackMode,
+    #[serde(rename = "normalizeLockKeys")]
+    normalize_lock_keys: bool,
+    #[serde(rename = "prebufferMs")]
+    prebuffer_ms: u32,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -449,6 +2178,48 @@ struct AudioState {
     minimize_to_tray: bool,
     overlap_mode: bool,
     crossfade_duration: u32,
+    extra_devices: Vec<String>,
+    playback_threads: usize,
+    max_concurrent_sounds: usize,
+    concurrency_policy: ConcurrencyPolicy,
+    group_order: Vec<String>,
+    stats_reset: StatsResetPeriod,
+    stats: PlayStats,
+    update_channel: String,
+    auto_check_updates: bool,
+    last_update_check: Option<u64>,
+    skipped_updates: Vec<String>,
+    key_mapping: KeyMapping,
+    grid_columns: u32,
+    ignore_key_repeat: bool,
+    mute_keybind: Option<String>,
+    tray_left_click_action: TrayClickAction,
+    also_play_default: bool,
+    ignore_when_typing: bool,
+    require_modifier_for_alnum: bool,
+    input_device: Option<String>,
+    quick_search_keybind: Option<String>,
+    device_gain: HashMap<String, f32>,
+    default_sound_volume: f32,
+    output_sync_ms: u32,
+    missing_device_behavior: MissingDeviceBehavior,
+    group_step_keybinds: HashMap<String, String>,
+    file_open_timeout_ms: u32,
+    compact_hide_unbound: bool,
+    device_configs: HashMap<String, DeviceConfig>,
+    stale_key_timeout_ms: u32,
+    last_import_dir: Option<String>,
+    max_play_seconds: Option<u64>,
+    group_show_keybinds: HashMap<String, String>,
+    monitor_downmix_mono: bool,
+    restore_playback_on_start: bool,
+    restore_playback_mode: RestorePlaybackMode,
+    normalize_lock_keys: bool,
+    prebuffer_ms: u32,
+    // Runtime only: skip the primary output and play on the monitor alone
+    monitor_only: bool,
+    // Runtime only: plays go here instead of the saved primary until cleared or restart
+    primary_device_override: Option<String>,
 }
 
 impl Default for AudioState {
@@ -464,6 +2235,46 @@ impl Default for AudioState {
             minimize_to_tray: false,
             overlap_mode: true,
             crossfade_duration: 0,
+            extra_devices: Vec::new(),
+            playback_threads: DEFAULT_PLAYBACK_THREADS,
+            max_concurrent_sounds: 0,
+            concurrency_policy: ConcurrencyPolicy::default(),
+            group_order: Vec::new(),
+            stats_reset: StatsResetPeriod::default(),
+            stats: PlayStats::default(),
+            update_channel: default_update_channel(),
+            auto_check_updates: false,
+            last_update_check: None,
+            skipped_updates: Vec::new(),
+            key_mapping: KeyMapping::default(),
+            grid_columns: default_grid_columns(),
+            ignore_key_repeat: true,
+            mute_keybind: None,
+            tray_left_click_action: TrayClickAction::default(),
+            also_play_default: false,
+            ignore_when_typing: false,
+            require_modifier_for_alnum: false,
+            input_device: None,
+            quick_search_keybind: None,
+            device_gain: HashMap::new(),
+            default_sound_volume: default_sound_volume(),
+            output_sync_ms: 0,
+            missing_device_behavior: MissingDeviceBehavior::default(),
+            group_step_keybinds: HashMap::new(),
+            file_open_timeout_ms: default_file_open_timeout_ms(),
+            compact_hide_unbound: false,
+            device_configs: HashMap::new(),
+            stale_key_timeout_ms: default_stale_key_timeout_ms(),
+            last_import_dir: None,
+            max_play_seconds: None,
+            group_show_keybinds: HashMap::new(),
+            monitor_downmix_mono: false,
+            restore_playback_on_start: false,
+            restore_playback_mode: RestorePlaybackMode::default(),
+            normalize_lock_keys: true,
+            prebuffer_ms: 0,
+            monitor_only: false,
+            primary_device_override: None,
         }
     }
 }
@@ -492,1243 +2303,6281 @@ fn get_audio_devices() -> Vec<AudioDevice> {
     devices
 }
 
-#[tauri::command]
-fn set_primary_device(device_name: String, state: State<AppState>) -> Result<(), String> {
-    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.primary_device = Some(device_name);
-    save_settings(&audio_state);
-    Ok(())
-}
+static IDENTIFY_RUNNING: AtomicBool = AtomicBool::new(false);
 
-#[tauri::command]
-fn set_monitor_device(device_name: String, state: State<AppState>) -> Result<(), String> {
-    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.monitor_device = if device_name.is_empty() {
-        None
-    } else {
-        Some(device_name)
-    };
-    save_settings(&audio_state);
-    Ok(())
+#[derive(Debug, Clone, Serialize)]
+struct IdentifyProgress {
+    device: Option<String>,
+    // 1-based, also the number of beeps the device plays
+    index: usize,
+    total: usize,
+    finished: bool,
 }
 
+// Beep each output device in turn, device N beeping N times, so the user can tell which
+// physical endpoint is which. Stop-all cancels it. The sounding device is announced through
+// "identify-device" events.
 #[tauri::command]
-fn set_master_volume(volume: f32, state: State<AppState>) -> Result<(), String> {
-    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.master_volume = volume.clamp(0.0, 1.0);
-    save_settings(&audio_state);
-    Ok(())
-}
+fn identify_devices(app_handle: AppHandle, engine: State<Engine>) -> Result<(), String> {
+    if IDENTIFY_RUNNING.swap(true, Ordering::SeqCst) {
+        return Err("Device identification is already running".to_string());
+    }
 
-#[tauri::command]
-fn get_sounds(state: State<AppState>) -> Vec<Sound> {
-    let audio_state = state.lock().unwrap();
-    let mut sounds: Vec<Sound> = audio_state.sounds.values().cloned().collect();
-    sounds.sort_by_key(|s| s.order);
-    sounds
+    let engine = engine.inner().clone();
+    let generation = engine.generation();
+    std::thread::spawn(move || {
+        use rodio::cpal::traits::{HostTrait, DeviceTrait};
+
+        // Devices are opened from the enumeration itself; looking them up by name could
+        // land on a different endpoint whose name contains this one
+        let devices: Vec<(String, rodio::cpal::Device)> = rodio::cpal::default_host()
+            .output_devices()
+            .map(|devices| devices.filter_map(|d| Some((d.name().ok()?, d))).collect())
+            .unwrap_or_default();
+        let total = devices.len();
+
+        for (i, (name, device)) in devices.iter().enumerate() {
+            if engine.stopped_since(generation) {
+                break;
+            }
+            let index = i + 1;
+            let _ = app_handle.emit_all("identify-device", IdentifyProgress {
+                device: Some(name.clone()),
+                index,
+                total,
+                finished: false,
+            });
+            if let Err(e) = beep_device(&engine, generation, device, name, index.min(8)) {
+                eprintln!("[Identify] {}: {}", name, e);
+            }
+            std::thread::sleep(std::time::Duration::from_millis(400));
+        }
+
+        IDENTIFY_RUNNING.store(false, Ordering::SeqCst);
+        let _ = app_handle.emit_all("identify-device", IdentifyProgress {
+            device: None,
+            index: total,
+            total,
+            finished: true,
+        });
+    });
+    Ok(())
 }
 
-#[tauri::command]
-fn get_settings(state: State<AppState>) -> Settings {
-    let audio_state = state.lock().unwrap();
-    Settings {
-        primary_device: audio_state.primary_device.clone(),
-        monitor_device: audio_state.monitor_device.clone(),
-        master_volume: audio_state.master_volume,
-        stop_all_keybind: audio_state.stop_all_keybind.clone(),
-        compact_mode: audio_state.compact_mode,
-        theme: audio_state.theme.clone(),
-        minimize_to_tray: audio_state.minimize_to_tray,
-        overlap_mode: audio_state.overlap_mode,
-        crossfade_duration: audio_state.crossfade_duration,
+// Play a run of short beeps on one device, stopping early on stop-all
+fn beep_device(engine: &Engine, generation: u64, device: &rodio::cpal::Device, name: &str, beeps: usize) -> Result<(), String> {
+    let (_stream, stream_handle) = OutputStream::try_from_device(device).map_err(|e| e.to_string())?;
+    let sink = Arc::new(Sink::try_new(&stream_handle).map_err(|e| e.to_string())?);
+    let _playback = engine.track("identify", Some(name), 0.3, &sink);
+    for _ in 0..beeps {
+        sink.append(rodio::source::SineWave::new(880.0).take_duration(std::time::Duration::from_millis(150)));
+        sink.append(rodio::source::Zero::<f32>::new(1, 48_000).take_duration(std::time::Duration::from_millis(150)));
     }
-}
 
-#[tauri::command]
-fn set_stop_all_keybind(keybind: Option<String>, state: State<AppState>) -> Result<(), String> {
-    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.stop_all_keybind = keybind;
-    save_settings(&audio_state);
+    while !sink.empty() {
+        if engine.stopped_since(generation) {
+            sink.stop();
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
     Ok(())
 }
 
+// Capture devices (microphones, loopback inputs), numbered like get_audio_devices
 #[tauri::command]
-fn set_compact_mode(enabled: bool, state: State<AppState>) -> Result<(), String> {
-    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.compact_mode = enabled;
-    save_settings(&audio_state);
-    Ok(())
-}
+fn get_input_devices() -> Vec<AudioDevice> {
+    let host = rodio::cpal::default_host();
+    let mut devices = Vec::new();
 
-#[tauri::command]
-fn set_theme(theme: String, state: State<AppState>) -> Result<(), String> {
+    use rodio::cpal::traits::{HostTrait, DeviceTrait};
+
+    if let Ok(input_devices) = host.input_devices() {
+        for (idx, device) in input_devices.enumerate() {
+            if let Ok(name) = device.name() {
+                devices.push(AudioDevice {
+                    id: idx as i32,
+                    name,
+                });
+            }
+        }
+    }
+
+    devices
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ConfiguredDevice {
+    name: String,
+    resolves: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Diagnostics {
+    version: String,
+    os: String,
+    arch: String,
+    #[serde(rename = "outputDevices")]
+    output_devices: Vec<String>,
+    #[serde(rename = "defaultDevice")]
+    default_device: Option<String>,
+    #[serde(rename = "primaryDevice")]
+    primary_device: Option<ConfiguredDevice>,
+    #[serde(rename = "monitorDevice")]
+    monitor_device: Option<ConfiguredDevice>,
+    #[serde(rename = "soundCount")]
+    sound_count: usize,
+    #[serde(rename = "registeredKeybinds")]
+    registered_keybinds: usize,
+    #[serde(rename = "listenerRunning")]
+    listener_running: bool,
+    #[serde(rename = "persistenceEnabled")]
+    persistence_enabled: bool,
+    #[serde(rename = "safeMode")]
+    safe_mode: bool,
+    // Configured names that are currently being played somewhere else
+    #[serde(rename = "deviceResolutions")]
+    device_resolutions: Vec<DeviceResolution>,
+    #[serde(rename = "resourceUsage")]
+    resource_usage: ResourceUsage,
+}
+
+// Environment summary for bug reports
+#[tauri::command]
+fn get_diagnostics(state: State<AppState>) -> Result<Diagnostics, String> {
+    let (primary, monitor, sound_count) = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        (
+            audio_state.primary_device.clone(),
+            audio_state.monitor_device.clone(),
+            audio_state.sounds.len(),
+        )
+    };
+    let configured = |name: Option<String>| {
+        name.map(|name| ConfiguredDevice {
+            resolves: find_device_by_name(&name).is_some(),
+            name,
+        })
+    };
+
+    Ok(Diagnostics {
+        version: VERSION.to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        output_devices: get_audio_devices().into_iter().map(|d| d.name).collect(),
+        default_device: default_output_device_name(),
+        primary_device: configured(primary),
+        monitor_device: configured(monitor),
+        sound_count,
+        registered_keybinds: KEYBIND_REGISTRY.lock().map(|r| r.len()).unwrap_or(0),
+        listener_running: LISTENER_RUNNING.load(Ordering::SeqCst),
+        persistence_enabled: should_persist(),
+        safe_mode: SAFE_MODE.load(Ordering::SeqCst),
+        device_resolutions: device_resolutions(),
+        resource_usage: resource_usage(),
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ResourceUsage {
+    // Resident memory of the process; None where it can't be read
+    #[serde(rename = "rssBytes")]
+    rss_bytes: Option<u64>,
+    // Share of total CPU since the previous sample (0 on the first one), 0-100
+    #[serde(rename = "cpuPercent")]
+    cpu_percent: Option<f64>,
+    #[serde(rename = "activeSinks")]
+    active_sinks: usize,
+    #[serde(rename = "playbackWorkers")]
+    playback_workers: usize,
+    #[serde(rename = "queuedPlays")]
+    queued_plays: usize,
+    #[serde(rename = "cachedDurations")]
+    cached_durations: usize,
+    #[serde(rename = "cachedSoundInfo")]
+    cached_sound_info: usize,
+}
+
+lazy_static::lazy_static! {
+    // Kept between samples so CPU usage covers the time since the previous one
+    static ref PROCESS_SAMPLER: Mutex<sysinfo::System> = Mutex::new(sysinfo::System::new());
+}
+
+// Resident memory and recent CPU share of this process
+fn process_usage() -> (Option<u64>, Option<f64>) {
+    let Ok(pid) = sysinfo::get_current_pid() else {
+        return (None, None);
+    };
+    let Ok(mut system) = PROCESS_SAMPLER.lock() else {
+        return (None, None);
+    };
+    if !system.refresh_process(pid) {
+        return (None, None);
+    }
+    let Some(process) = system.process(pid) else {
+        return (None, None);
+    };
+    // cpu_usage is per core, so scale it to a share of the whole machine
+    let cores = std::thread::available_parallelism().map_or(1, |n| n.get()) as f64;
+    let percent = (f64::from(process.cpu_usage()) / cores).min(100.0);
+    (Some(process.memory()), Some(percent))
+}
+
+fn resource_usage() -> ResourceUsage {
+    let (rss_bytes, cpu_percent) = process_usage();
+    let (playback_workers, queued_plays) = PLAYBACK_POOL
+        .state
+        .lock()
+        .map(|pool| (pool.workers, pool.jobs.len()))
+        .unwrap_or_default();
+    ResourceUsage {
+        rss_bytes,
+        cpu_percent,
+        active_sinks: audio_engine()
+            .and_then(|engine| engine.playbacks.lock().ok().map(|p| p.len()))
+            .unwrap_or(0),
+        playback_workers,
+        queued_plays,
+        cached_durations: DURATION_CACHE.lock().map(|c| c.len()).unwrap_or(0),
+        cached_sound_info: SOUND_INFO_CACHE.lock().map(|c| c.len()).unwrap_or(0),
+    }
+}
+
+// Memory/CPU footprint next to what the audio side is holding, for slow-PC reports
+#[tauri::command]
+fn get_resource_usage() -> ResourceUsage {
+    resource_usage()
+}
+
+// Enforces the play-time cap for every tracked sink
+fn start_play_cap_watcher() {
+    std::thread::spawn(|| loop {
+        std::thread::sleep(std::time::Duration::from_millis(250));
+        if let Some(engine) = audio_engine() {
+            engine.stop_overdue();
+        }
+    });
+}
+
+// Debug aid: push resource usage to the frontend so it can be graphed against plays
+fn start_resource_usage_emitter() {
+    if !DEBUG_RESOURCES.load(Ordering::SeqCst) {
+        return;
+    }
+    std::thread::spawn(|| loop {
+        std::thread::sleep(std::time::Duration::from_secs(5));
+        if let Some(app_handle) = APP_HANDLE.get() {
+            let _ = app_handle.emit_all("resource-usage", resource_usage());
+        }
+    });
+}
+
+// The primary output plays should use right now: the temporary override, else the saved one
+fn active_primary_device(state: &AudioState) -> Option<String> {
+    state
+        .primary_device_override
+        .clone()
+        .or_else(|| state.primary_device.clone())
+}
+
+// Send everything to another output for now without touching the saved primary.
+// None goes back to the saved device; the override never outlives the app.
+#[tauri::command]
+fn set_temporary_primary_device(device: Option<String>, state: State<AppState>) -> Result<(), String> {
+    let device = device.filter(|d| !d.trim().is_empty());
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.theme = theme;
-    save_settings(&audio_state);
+    audio_state.primary_device_override = device.clone();
+    drop(audio_state);
+
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let _ = app_handle.emit_all("device-override", device);
+    }
     Ok(())
 }
 
 #[tauri::command]
-fn set_minimize_to_tray(enabled: bool, state: State<AppState>) -> Result<(), String> {
+fn get_temporary_primary_device(state: State<AppState>) -> Result<Option<String>, String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    Ok(audio_state.primary_device_override.clone())
+}
+
+#[tauri::command]
+fn set_primary_device(device_name: String, state: State<AppState>) -> Result<(), String> {
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.minimize_to_tray = enabled;
+    audio_state.primary_device = Some(device_name);
     save_settings(&audio_state);
     Ok(())
 }
 
+// Input used by capture features, None for the system default
 #[tauri::command]
-fn set_overlap_mode(enabled: bool, state: State<AppState>) -> Result<(), String> {
+fn set_input_device(device_name: Option<String>, state: State<AppState>) -> Result<(), String> {
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.overlap_mode = enabled;
+    audio_state.input_device = device_name;
     save_settings(&audio_state);
     Ok(())
 }
 
+// Pre-roll in ms that lets every output start a trigger at the same moment, 0 to disable.
+// Higher values line devices up more reliably at the cost of that much latency.
 #[tauri::command]
-fn set_crossfade_duration(duration: u32, state: State<AppState>) -> Result<(), String> {
+fn set_output_sync(ms: u32, state: State<AppState>, engine: State<Engine>) -> Result<(), String> {
+    let ms = ms.min(250);
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.crossfade_duration = duration;
+    audio_state.output_sync_ms = ms;
+    engine.output_sync_ms.store(ms as u64, Ordering::SeqCst);
     save_settings(&audio_state);
     Ok(())
 }
 
+// Let the driver queue this many ms of audio before output begins, for virtual cables that
+// clip the first syllable. Adds the same amount of latency to every play.
 #[tauri::command]
-fn add_sound_from_path(file_path: String, state: State<AppState>) -> Result<Sound, String> {
-    let path = PathBuf::from(&file_path);
-
-    if !path.exists() {
-        return Err("File not found".to_string());
-    }
-
-    let name = path
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("Untitled")
-        .to_string();
-
-    // Calculate order based on current sound count
-    let order = {
-        let audio_state = state.lock().map_err(|e| e.to_string())?;
-        audio_state.sounds.len() as i32
-    };
-
-    let sound = Sound {
-        id: Uuid::new_v4().to_string(),
-        name,
-        keybind: None,
-        volume: 1.0,
-        file_path,
-        start_time: None,
-        end_time: None,
-        order,
-        loop_mode: false,
-        playback_speed: 1.0,
-        echo_delay: 0.0,
-        echo_volume: 0.0,
-        reverb_decay: 0.0,
-        bass_boost: 0.0,
-        fake_bass_boost: 0.0,
-    };
-
+fn set_prebuffer_ms(ms: u32, state: State<AppState>, engine: State<Engine>) -> Result<(), String> {
+    let ms = ms.min(1000);
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.sounds.insert(sound.id.clone(), sound.clone());
-    save_sounds(&audio_state.sounds);
-
-    Ok(sound)
+    audio_state.prebuffer_ms = ms;
+    engine.prebuffer_ms.store(ms as u64, Ordering::SeqCst);
+    save_settings(&audio_state);
+    Ok(())
 }
 
 #[tauri::command]
-fn remove_sound(sound_id: String, state: State<AppState>) -> Result<(), String> {
+fn set_missing_device_behavior(
+    behavior: MissingDeviceBehavior,
+    state: State<AppState>,
+    engine: State<Engine>,
+) -> Result<(), String> {
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    audio_state.sounds.remove(&sound_id);
-    save_sounds(&audio_state.sounds);
+    audio_state.missing_device_behavior = behavior;
+    *engine.missing_device_behavior.lock().map_err(|e| e.to_string())? = behavior;
+    save_settings(&audio_state);
     Ok(())
 }
 
+// How long opening a sound file may take before playback gives up, 0 disables the limit
 #[tauri::command]
-fn update_sound_keybind(
-    sound_id: String,
-    keybind: Option<String>,
-    state: State<AppState>,
-) -> Result<(), String> {
+fn set_file_open_timeout(ms: u32, state: State<AppState>) -> Result<(), String> {
+    let ms = clamp_file_open_timeout(ms);
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    if let Some(sound) = audio_state.sounds.get_mut(&sound_id) {
-        sound.keybind = keybind;
-    }
-    save_sounds(&audio_state.sounds);
+    audio_state.file_open_timeout_ms = ms;
+    FILE_OPEN_TIMEOUT_MS.store(ms as u64, Ordering::SeqCst);
+    save_settings(&audio_state);
     Ok(())
 }
 
 #[tauri::command]
-fn update_sound_trim(
-    sound_id: String,
-    start_time: Option<f64>,
-    end_time: Option<f64>,
-    state: State<AppState>,
-) -> Result<(), String> {
+fn is_primary_device_unavailable(engine: State<Engine>) -> bool {
+    engine.primary_unavailable().is_some()
+}
+
+// Level trim applied to everything played on one output device, 1.0 clears it
+#[tauri::command]
+fn set_device_gain(device: String, gain: f32, state: State<AppState>, engine: State<Engine>) -> Result<(), String> {
+    let gain = gain.clamp(0.0, 2.0);
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    if let Some(sound) = audio_state.sounds.get_mut(&sound_id) {
-        sound.start_time = start_time;
-        sound.end_time = end_time;
+    audio_state.device_gain.retain(|name, _| !name.eq_ignore_ascii_case(&device));
+    if gain != 1.0 {
+        audio_state.device_gain.insert(device, gain);
     }
-    save_sounds(&audio_state.sounds);
+    *engine.device_gains.lock().map_err(|e| e.to_string())? = audio_state.device_gain.clone();
+    save_settings(&audio_state);
     Ok(())
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct SupportedDeviceConfig {
+    channels: u16,
+    #[serde(rename = "sampleFormat")]
+    sample_format: String,
+    #[serde(rename = "minSampleRate")]
+    min_sample_rate: u32,
+    #[serde(rename = "maxSampleRate")]
+    max_sample_rate: u32,
+    // None when the driver doesn't report a range
+    #[serde(rename = "minBufferSize")]
+    min_buffer_size: Option<u32>,
+    #[serde(rename = "maxBufferSize")]
+    max_buffer_size: Option<u32>,
+}
+
+// Output formats a device supports, for picking a sample rate
 #[tauri::command]
-fn update_sound_settings(
-    sound_id: String,
-    volume: f32,
-    loop_mode: bool,
-    playback_speed: f32,
-    echo_delay: f32,
-    echo_volume: f32,
-    reverb_decay: f32,
-    bass_boost: f32,
-    fake_bass_boost: f32,
+fn get_device_configs(device_name: String) -> Result<Vec<SupportedDeviceConfig>, String> {
+    use rodio::cpal::traits::DeviceTrait;
+    use rodio::cpal::SupportedBufferSize;
+
+    let device = find_device_by_name(&device_name).ok_or_else(|| format!("Device not found: {}", device_name))?;
+    let ranges = device.supported_output_configs().map_err(|e| e.to_string())?;
+    Ok(ranges
+        .map(|range| {
+            let (min_buffer_size, max_buffer_size) = match *range.buffer_size() {
+                SupportedBufferSize::Range { min, max } => (Some(min), Some(max)),
+                SupportedBufferSize::Unknown => (None, None),
+            };
+            SupportedDeviceConfig {
+                channels: range.channels(),
+                sample_format: range.sample_format().to_string(),
+                min_sample_rate: range.min_sample_rate().0,
+                max_sample_rate: range.max_sample_rate().0,
+                min_buffer_size,
+                max_buffer_size,
+            }
+        })
+        .collect())
+}
+
+// Open a device at a specific sample rate, or back at its default with None. rodio 0.17 always
+// opens streams with the driver's default buffer, so the buffer size can't be chosen.
+#[tauri::command]
+fn set_device_config(
+    device_name: String,
+    sample_rate: Option<u32>,
     state: State<AppState>,
+    engine: State<Engine>,
 ) -> Result<(), String> {
+    let config = sample_rate.map(|sample_rate| DeviceConfig { sample_rate });
+
+    // Only check against the device when it's plugged in; a missing device can still be configured
+    if let Some(config) = config.as_ref().filter(|_| find_device_by_name(&device_name).is_some()) {
+        let supported = get_device_configs(device_name.clone())?;
+        let rate_ok = supported
+            .iter()
+            .any(|c| c.min_sample_rate <= config.sample_rate && config.sample_rate <= c.max_sample_rate);
+        if !rate_ok {
+            return Err(format!("{} Hz isn't supported by {}", config.sample_rate, device_name));
+        }
+    }
+
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    if let Some(sound) = audio_state.sounds.get_mut(&sound_id) {
-        sound.volume = volume.clamp(0.0, 2.0); // Allow up to 200%
-        sound.loop_mode = loop_mode;
-        sound.playback_speed = playback_speed.clamp(0.25, 2.0); // 0.25x to 2x speed
-        sound.echo_delay = echo_delay.clamp(0.0, 1.0); // 0 to 1 second delay
-        sound.echo_volume = echo_volume.clamp(0.0, 1.0); // Echo volume 0-100%
-        sound.reverb_decay = reverb_decay.clamp(0.0, 0.9); // Reverb decay 0-90%
-        sound.bass_boost = bass_boost.clamp(0.0, 3.0); // Bass boost 0-300%
-        sound.fake_bass_boost = fake_bass_boost.clamp(0.0, 10.0); // Fake bass boost 0-1000% (extreme!)
+    audio_state.device_configs.retain(|name, _| !name.eq_ignore_ascii_case(&device_name));
+    if let Some(config) = config {
+        audio_state.device_configs.insert(device_name, config);
     }
-    save_sounds(&audio_state.sounds);
+    *engine.device_configs.lock().map_err(|e| e.to_string())? = audio_state.device_configs.clone();
+    save_settings(&audio_state);
     Ok(())
 }
 
 #[tauri::command]
-fn update_sound_order(sound_ids: Vec<String>, state: State<AppState>) -> Result<(), String> {
+fn set_monitor_device(device_name: String, state: State<AppState>) -> Result<(), String> {
     let mut audio_state = state.lock().map_err(|e| e.to_string())?;
-    for (index, sound_id) in sound_ids.iter().enumerate() {
-        if let Some(sound) = audio_state.sounds.get_mut(sound_id) {
-            sound.order = index as i32;
+    audio_state.monitor_device = if device_name.is_empty() {
+        None
+    } else {
+        Some(device_name)
+    };
+    if audio_state.monitor_device.is_none() {
+        audio_state.monitor_only = false;
+    }
+    save_settings(&audio_state);
+
+    // Playback skips a monitor that is really the primary, so tell the UI instead of going silent
+    if let Some(ref monitor) = audio_state.monitor_device {
+        if monitor_equals_primary(audio_state.primary_device.as_deref(), monitor) {
+            if let Some(app_handle) = APP_HANDLE.get() {
+                let _ = app_handle.emit_all("monitor-equals-primary", monitor.clone());
+            }
         }
     }
-    save_sounds(&audio_state.sounds);
     Ok(())
 }
 
-// Warm up audio device by playing a very short silent tone
-// This initializes the audio pipeline and prevents first-play issues
-fn warmup_audio_device(device_name: Option<&str>) {
-    std::thread::spawn({
-        let device = device_name.map(|s| s.to_string());
-        move || {
-            let (_stream, stream_handle): (OutputStream, OutputStreamHandle) = if let Some(ref name) = device {
-                if let Some(dev) = find_device_by_name(name) {
-                    match OutputStream::try_from_device(&dev) {
-                        Ok(s) => s,
-                        Err(_) => return,
-                    }
-                } else {
-                    return;
-                }
-            } else {
-                return;
-            };
+// Whether a monitor device name resolves to the same output as the primary (None = default)
+fn monitor_equals_primary(primary: Option<&str>, monitor: &str) -> bool {
+    use rodio::cpal::traits::DeviceTrait;
 
-            if let Ok(sink) = Sink::try_new(&stream_handle) {
-                // Play a very short, very quiet sine wave to initialize the device
-                let source = SineWave::new(440.0)
-                    .take_duration(std::time::Duration::from_millis(50))
-                    .amplify(0.001); // Nearly silent
-                sink.append(source);
-                sink.sleep_until_end();
-            }
-        }
-    });
+    let resolve = |name: &str| find_device_by_name(name).and_then(|d| d.name().ok());
+    let primary_name = match primary {
+        Some(primary) => resolve(primary).or_else(|| Some(primary.to_string())),
+        None => default_output_device_name(),
+    };
+    let monitor_name = resolve(monitor).unwrap_or_else(|| monitor.to_string());
+    primary_name.is_some_and(|p| p.eq_ignore_ascii_case(&monitor_name))
 }
 
-fn find_device_by_name(name: &str) -> Option<rodio::cpal::Device> {
-    use rodio::cpal::traits::{HostTrait, DeviceTrait};
+// Update monitor-only mode and its tray checkmark
+fn apply_monitor_only(app_handle: &AppHandle, audio_state: &mut AudioState, enabled: bool) -> Result<(), String> {
+    if enabled && audio_state.monitor_device.is_none() {
+        return Err("Set a monitor device before enabling monitor-only mode".to_string());
+    }
+    audio_state.monitor_only = enabled;
+    let _ = app_handle.tray_handle().get_item("monitor_only").set_selected(enabled);
+    Ok(())
+}
 
-    let host = rodio::cpal::default_host();
-    let name_lower = name.to_lowercase();
+#[tauri::command]
+fn set_monitor_only(app_handle: AppHandle, enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    apply_monitor_only(&app_handle, &mut audio_state, enabled)
+}
 
-    host.output_devices().ok()?.find(|d| {
-        d.name()
-            .map(|n| n.to_lowercase().contains(&name_lower))
-            .unwrap_or(false)
-    })
+// Fold the monitor output down to mono, for single-speaker monitors that drop a channel
+#[tauri::command]
+fn set_monitor_downmix_mono(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.monitor_downmix_mono = enabled;
+    save_settings(&audio_state);
+    Ok(())
 }
 
-fn play_on_device(
-    file_path: &str,
-    device_name: Option<&str>,
-    volume: f32,
-    start_time: Option<f64>,
-    end_time: Option<f64>,
-    loop_mode: bool,
-    playback_speed: f32,
-) -> Result<(), String> {
-    let file = File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?;
-    let source = Decoder::new(BufReader::new(file))
-        .map_err(|e| format!("Failed to decode audio: {}", e))?;
+// The monitor output, when it should get a mono downmix
+fn mono_monitor_device(state: &AudioState) -> Option<String> {
+    state.monitor_downmix_mono.then(|| state.monitor_device.clone()).flatten()
+}
 
-    // Try to use specific device, fall back to default
-    let (_stream, stream_handle): (OutputStream, OutputStreamHandle) = if let Some(name) = device_name {
-        if let Some(device) = find_device_by_name(name) {
-            OutputStream::try_from_device(&device)
-                .map_err(|e| format!("Failed to open device: {}", e))?
-        } else {
-            // Fall back to default if device not found
-            OutputStream::try_default()
-                .map_err(|e| format!("Failed to open default device: {}", e))?
-        }
+#[tauri::command]
+fn set_also_play_default(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.also_play_default = enabled;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Additional outputs played alongside primary and monitor (e.g. a capture device)
+#[tauri::command]
+fn set_extra_devices(names: Vec<String>, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let mut extra_devices: Vec<String> = Vec::new();
+    for name in names {
+        let name = name.trim().to_string();
+        if name.is_empty() || extra_devices.iter().any(|d| d.eq_ignore_ascii_case(&name)) {
+            continue;
+        }
+        extra_devices.push(name);
+    }
+    audio_state.extra_devices = extra_devices;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_master_volume(volume: f32, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.master_volume = volume.clamp(0.0, 1.0);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_sounds(state: State<AppState>) -> Vec<Sound> {
+    let audio_state = state.lock().unwrap();
+    let mut sounds: Vec<Sound> = audio_state.sounds.values().cloned().collect();
+    sounds.sort_by_key(|s| s.order);
+    sounds
+}
+
+// Sounds the pad grid shows: everything, or only bound pads in compact mode with
+// compact_hide_unbound on
+#[tauri::command]
+fn get_visible_sounds(state: State<AppState>) -> Result<Vec<Sound>, String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    let hide_unbound = audio_state.compact_mode && audio_state.compact_hide_unbound;
+    let mut sounds: Vec<Sound> = audio_state
+        .sounds
+        .values()
+        .filter(|s| !hide_unbound || s.keybind.is_some())
+        .cloned()
+        .collect();
+    sounds.sort_by_key(|s| s.order);
+    Ok(sounds)
+}
+
+#[tauri::command]
+fn get_settings(state: State<AppState>) -> Settings {
+    let audio_state = state.lock().unwrap();
+    Settings {
+        primary_device: audio_state.primary_device.clone(),
+        monitor_device: audio_state.monitor_device.clone(),
+        master_volume: audio_state.master_volume,
+        stop_all_keybind: audio_state.stop_all_keybind.clone(),
+        compact_mode: audio_state.compact_mode,
+        theme: audio_state.theme.clone(),
+        minimize_to_tray: audio_state.minimize_to_tray,
+        overlap_mode: audio_state.overlap_mode,
+        crossfade_duration: audio_state.crossfade_duration,
+        extra_devices: audio_state.extra_devices.clone(),
+        monitor_only: audio_state.monitor_only,
+        playback_threads: audio_state.playback_threads,
+        max_concurrent_sounds: audio_state.max_concurrent_sounds,
+        concurrency_policy: audio_state.concurrency_policy,
+        group_order: audio_state.group_order.clone(),
+        stats_reset: audio_state.stats_reset,
+        update_channel: audio_state.update_channel.clone(),
+        auto_check_updates: audio_state.auto_check_updates,
+        skipped_updates: audio_state.skipped_updates.clone(),
+        key_mapping: audio_state.key_mapping,
+        grid_columns: audio_state.grid_columns,
+        ignore_key_repeat: audio_state.ignore_key_repeat,
+        mute_keybind: audio_state.mute_keybind.clone(),
+        tray_left_click_action: audio_state.tray_left_click_action,
+        also_play_default: audio_state.also_play_default,
+        ignore_when_typing: audio_state.ignore_when_typing,
+        require_modifier_for_alnum: audio_state.require_modifier_for_alnum,
+        input_device: audio_state.input_device.clone(),
+        quick_search_keybind: audio_state.quick_search_keybind.clone(),
+        device_gain: audio_state.device_gain.clone(),
+        default_sound_volume: audio_state.default_sound_volume,
+        output_sync_ms: audio_state.output_sync_ms,
+        missing_device_behavior: audio_state.missing_device_behavior,
+        group_step_keybinds: audio_state.group_step_keybinds.clone(),
+        file_open_timeout_ms: audio_state.file_open_timeout_ms,
+        compact_hide_unbound: audio_state.compact_hide_unbound,
+        device_configs: audio_state.device_configs.clone(),
+        stale_key_timeout_ms: audio_state.stale_key_timeout_ms,
+        last_import_dir: audio_state.last_import_dir.clone(),
+        max_play_seconds: audio_state.max_play_seconds,
+        group_show_keybinds: audio_state.group_show_keybinds.clone(),
+        monitor_downmix_mono: audio_state.monitor_downmix_mono,
+        restore_playback_on_start: audio_state.restore_playback_on_start,
+        restore_playback_mode: audio_state.restore_playback_mode,
+        normalize_lock_keys: audio_state.normalize_lock_keys,
+        prebuffer_ms: audio_state.prebuffer_ms,
+    }
+}
+
+#[tauri::command]
+fn is_safe_mode() -> bool {
+    SAFE_MODE.load(Ordering::SeqCst)
+}
+
+// Leave safe mode by saving the current board over the existing files
+#[tauri::command]
+fn save_safe_mode_board(state: State<AppState>) -> Result<(), String> {
+    if !SAFE_MODE.swap(false, Ordering::SeqCst) {
+        return Err("Not in safe mode".to_string());
+    }
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    save_sounds(&audio_state.sounds);
+    save_settings(&audio_state);
+    save_stats(&audio_state.stats);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_stop_all_keybind(keybind: Option<String>, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    if let Some(ref keybind) = keybind {
+        let ignore = HashSet::from(["STOP_ALL".to_string()]);
+        if let Some(owner) = find_keybind_conflict(&audio_state, keybind, &ignore) {
+            return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+        }
+    }
+    audio_state.stop_all_keybind = keybind;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_key_mapping(mapping: KeyMapping, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.key_mapping = mapping;
+    LAYOUT_KEY_MAPPING.store(mapping == KeyMapping::Layout, Ordering::SeqCst);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_grid_columns(columns: u32, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.grid_columns = columns.clamp(1, 12);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_ignore_key_repeat(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.ignore_key_repeat = enabled;
+    IGNORE_KEY_REPEAT.store(enabled, Ordering::SeqCst);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_normalize_lock_keys(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.normalize_lock_keys = enabled;
+    NORMALIZE_LOCK_KEYS.store(enabled, Ordering::SeqCst);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_ignore_when_typing(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.ignore_when_typing = enabled;
+    IGNORE_WHEN_TYPING.store(enabled, Ordering::SeqCst);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Called by the frontend on focus/blur of its editable fields
+#[tauri::command]
+fn set_text_input_focused(focused: bool) {
+    TEXT_INPUT_FOCUSED.store(focused, Ordering::SeqCst);
+}
+
+#[tauri::command]
+fn set_require_modifier_for_alnum(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.require_modifier_for_alnum = enabled;
+    REQUIRE_MODIFIER_FOR_ALNUM.store(enabled, Ordering::SeqCst);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_compact_mode(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.compact_mode = enabled;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Stop any sound after this many seconds, None (or 0) for no limit
+#[tauri::command]
+fn set_max_play_seconds(seconds: Option<u64>, state: State<AppState>, engine: State<Engine>) -> Result<(), String> {
+    let seconds = seconds.filter(|s| *s > 0);
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.max_play_seconds = seconds;
+    engine.max_play_secs.store(seconds.unwrap_or(0), Ordering::SeqCst);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Idle time after which held keys are forgotten, 0 turns the sweep off
+#[tauri::command]
+fn set_stale_key_timeout(ms: u32, state: State<AppState>) -> Result<(), String> {
+    let ms = clamp_stale_key_timeout(ms);
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.stale_key_timeout_ms = ms;
+    STALE_KEY_TIMEOUT_MS.store(ms as u64, Ordering::SeqCst);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Folder the add-sound picker opens in
+#[tauri::command]
+fn set_last_import_dir(dir: Option<String>, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.last_import_dir = dir.filter(|d| !d.trim().is_empty());
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_compact_hide_unbound(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.compact_hide_unbound = enabled;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_theme(theme: String, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.theme = theme;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_tray_left_click_action(action: TrayClickAction, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.tray_left_click_action = action;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_minimize_to_tray(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.minimize_to_tray = enabled;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Volume given to newly added sounds. Existing sounds keep their own volume.
+#[tauri::command]
+fn set_default_sound_volume(volume: f32, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.default_sound_volume = volume.clamp(0.0, 2.0);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_overlap_mode(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.overlap_mode = enabled;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_playback_threads(count: usize, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.playback_threads = count.clamp(1, 64);
+    PLAYBACK_POOL.resize(audio_state.playback_threads);
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Cap on simultaneous sounds, 0 means unlimited
+#[tauri::command]
+fn set_max_concurrent(
+    count: usize,
+    policy: Option<ConcurrencyPolicy>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.max_concurrent_sounds = count;
+    if let Some(policy) = policy {
+        audio_state.concurrency_policy = policy;
+    }
+    save_settings(&audio_state);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_crossfade_duration(duration: u32, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.crossfade_duration = duration;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Extensions the decoders can open. WebM/Matroska is demuxed by symphonia; Opus, in Ogg
+// or WebM, goes through OpusSource since rodio can't decode it.
+const SUPPORTED_EXTENSIONS: [&str; 7] = ["mp3", "wav", "ogg", "flac", "webm", "mka", "opus"];
+
+fn check_supported_audio(path: &std::path::Path) -> Result<(), String> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    if !SUPPORTED_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(format!("Unsupported audio format: .{}", extension));
+    }
+    // Containers can hold codecs we can't decode, so check they actually open
+    if extension == "webm" || extension == "mka" {
+        open_decoder(&path.to_string_lossy())
+            .map_err(|_| "Could not decode this file. WebM/Matroska clips must use Vorbis or Opus audio".to_string())?;
+    }
+    Ok(())
+}
+
+// Whether add_sound_from_path would accept a file, so drag-and-drop can reject it up front with
+// the exact reason. Only the format header is probed, nothing is decoded.
+#[tauri::command]
+fn can_add_sound(file_path: String) -> Result<(), PlaybackError> {
+    let path = PathBuf::from(&file_path);
+    if !path.is_file() {
+        return Err(PlaybackError::File(format!("File not found: {}", file_path)));
+    }
+    check_supported_audio(&path).map_err(PlaybackError::Unsupported)?;
+    open_decoder(&file_path)?;
+    Ok(())
+}
+
+// A pad with default settings for a newly added file, at the configured default volume
+fn new_sound(name: String, file_path: String, order: i32, volume: f32) -> Sound {
+    Sound {
+        id: Uuid::new_v4().to_string(),
+        name,
+        keybind: None,
+        volume,
+        file_path,
+        start_time: None,
+        end_time: None,
+        order,
+        loop_mode: false,
+        playback_speed: 1.0,
+        echo_delay: 0.0,
+        echo_volume: 0.0,
+        reverb_decay: 0.0,
+        bass_boost: 0.0,
+        fake_bass_boost: 0.0,
+        exclusive_group: None,
+        random_start: false,
+        start_sample: None,
+        end_sample: None,
+        group: None,
+        notify_on_play: false,
+        created_at: unix_now_millis(),
+        on_play_webhook: None,
+        color: None,
+        active_window_filter: None,
+        trigger_on_release: false,
+    }
+}
+
+#[tauri::command]
+fn add_sound_from_path(file_path: String, state: State<AppState>) -> Result<Sound, String> {
+    let path = PathBuf::from(&file_path);
+
+    if !path.exists() {
+        return Err("File not found".to_string());
+    }
+    check_supported_audio(&path)?;
+
+    let name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("Untitled")
+        .to_string();
+
+    // Calculate order based on current sound count
+    let (order, volume) = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        (audio_state.sounds.len() as i32, audio_state.default_sound_volume)
+    };
+
+    let sound = new_sound(name, file_path, order, volume);
+
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.sounds.insert(sound.id.clone(), sound.clone());
+    save_sounds(&audio_state.sounds);
+
+    // The file picker reopens here next time
+    let import_dir = path.parent().map(|dir| dir.to_string_lossy().to_string());
+    if import_dir.is_some() && import_dir != audio_state.last_import_dir {
+        audio_state.last_import_dir = import_dir;
+        save_settings(&audio_state);
+    }
+
+    spawn_clipping_check(&sound);
+    Ok(sound)
+}
+
+// Only this much of a file is scanned, so a long recording doesn't keep a thread busy
+const CLIP_SCAN_SECS: u64 = 60;
+// Consecutive full-scale samples (interleaved) that count as a clipped stretch rather than a lone peak
+const CLIP_RUN_SAMPLES: usize = 8;
+
+#[derive(Debug, Clone, Serialize)]
+struct ClippingWarning {
+    #[serde(rename = "soundId")]
+    sound_id: String,
+    name: String,
+    // Peak level in dBFS (0.0 = full scale)
+    #[serde(rename = "peakDb")]
+    peak_db: f32,
+    #[serde(rename = "clippedRegions")]
+    clipped_regions: usize,
+}
+
+// Peak level and number of sustained full-scale stretches in the start of a file
+fn scan_clipping(file_path: &str) -> Option<(f32, usize)> {
+    let source = open_decoder(file_path).ok()?;
+    let limit = source.sample_rate() as u64 * source.channels() as u64 * CLIP_SCAN_SECS;
+
+    let mut peak: u16 = 0;
+    let mut run = 0;
+    let mut regions = 0;
+    for sample in source.take(limit as usize) {
+        let level = sample.unsigned_abs();
+        peak = peak.max(level);
+        if level >= i16::MAX as u16 {
+            run += 1;
+            if run == CLIP_RUN_SAMPLES {
+                regions += 1;
+            }
+        } else {
+            run = 0;
+        }
+    }
+    let peak_db = 20.0 * (peak.max(1) as f32 / i16::MAX as f32).log10();
+    Some((peak_db, regions))
+}
+
+// Check a freshly imported clip for clipping in the background and warn the UI about it
+// through a "clipping-warning" event, so the import itself isn't slowed down
+fn spawn_clipping_check(sound: &Sound) {
+    let sound_id = sound.id.clone();
+    let name = sound.name.clone();
+    let file_path = sound.file_path.clone();
+    std::thread::spawn(move || {
+        let Some((peak_db, clipped_regions)) = scan_clipping(&file_path) else {
+            return;
+        };
+        if clipped_regions == 0 {
+            return;
+        }
+        if let Some(app_handle) = APP_HANDLE.get() {
+            let _ = app_handle.emit_all(
+                "clipping-warning",
+                ClippingWarning {
+                    sound_id,
+                    name,
+                    peak_db,
+                    clipped_regions,
+                },
+            );
+        }
+    });
+}
+
+// First run means no saved board yet
+#[tauri::command]
+fn is_first_run() -> bool {
+    get_config_dir()
+        .map(|dir| !dir.join("sounds.json").exists())
+        .unwrap_or(true)
+}
+
+// Example clips are generated rather than shipped: (file name, pad name, notes)
+type ToneNotes = &'static [(f32, f32)]; // (Hz, seconds)
+
+const EXAMPLE_SOUNDS: [(&str, &str, ToneNotes); 2] = [
+    ("example-chime.wav", "Example Chime", &[(659.25, 0.18), (880.0, 0.35)]),
+    ("example-alert.wav", "Example Alert", &[(440.0, 0.12), (440.0, 0.12), (587.33, 0.3)]),
+];
+
+// Write a 16-bit mono WAV of the given notes, each with a short fade to avoid clicks
+fn write_tone_wav(path: &std::path::Path, notes: &[(f32, f32)]) -> std::io::Result<()> {
+    const SAMPLE_RATE: u32 = 44100;
+    let mut samples: Vec<i16> = Vec::new();
+    for &(freq, secs) in notes {
+        let count = (secs * SAMPLE_RATE as f32) as usize;
+        let fade = (count / 10).max(1);
+        for i in 0..count {
+            let envelope = (i.min(count - i) as f32 / fade as f32).min(1.0);
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let value = (2.0 * std::f32::consts::PI * freq * t).sin() * envelope * 0.5;
+            samples.push((value * i16::MAX as f32) as i16);
+        }
+    }
+
+    write_wav(path, 1, SAMPLE_RATE, &samples)
+}
+
+// Write interleaved 16-bit PCM samples as a WAV file
+fn write_wav(path: &std::path::Path, channels: u16, sample_rate: u32, samples: &[i16]) -> std::io::Result<()> {
+    let data_len = (samples.len() * 2) as u32;
+    let block_align = channels * 2;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    bytes.extend_from_slice(&channels.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+    bytes.extend_from_slice(&block_align.to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    File::create(path)?.write_all(&bytes)
+}
+
+// Add the example pads to the library. Only runs when the frontend asks, and skips
+// examples that already have a pad so calling it twice doesn't duplicate them.
+#[tauri::command]
+fn seed_example_sounds(state: State<AppState>) -> Result<Vec<Sound>, String> {
+    let library_dir = ensure_library_dir().ok_or_else(|| "Could not create sound library".to_string())?;
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+
+    let mut added = Vec::new();
+    for (file_name, name, notes) in EXAMPLE_SOUNDS {
+        let path = library_dir.join(file_name);
+        let file_path = path.to_string_lossy().to_string();
+        if audio_state.sounds.values().any(|s| s.file_path == file_path) {
+            continue;
+        }
+        if !path.exists() {
+            write_tone_wav(&path, notes).map_err(|e| format!("Failed to write example sound: {}", e))?;
+        }
+
+        let order = audio_state.sounds.len() as i32;
+        let sound = new_sound(name.to_string(), file_path, order, audio_state.default_sound_volume);
+        audio_state.sounds.insert(sound.id.clone(), sound.clone());
+        added.push(sound);
+    }
+
+    if !added.is_empty() {
+        save_sounds(&audio_state.sounds);
+    }
+    Ok(added)
+}
+
+// Something a batch operation couldn't handle (a sound name or id, file, keybind or CSV
+// line), and why
+#[derive(Debug, Clone, Serialize)]
+struct ItemFailure {
+    entry: String,
+    reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+struct MigrationReport {
+    migrated: Vec<String>,
+    failed: Vec<ItemFailure>,
+    #[serde(rename = "alreadyManaged")]
+    already_managed: usize,
+}
+
+// Copy externally referenced files into the managed library and repoint the sounds at them
+#[tauri::command]
+fn migrate_to_managed_library(state: State<AppState>) -> Result<MigrationReport, String> {
+    let mut report = MigrationReport::default();
+
+    // Copy outside the lock, large libraries take a while
+    let external: Vec<(String, String, String)> = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state
+            .sounds
+            .values()
+            .filter(|s| {
+                let managed = is_in_library(&s.file_path);
+                if managed {
+                    report.already_managed += 1;
+                }
+                !managed
+            })
+            .map(|s| (s.id.clone(), s.name.clone(), s.file_path.clone()))
+            .collect()
+    };
+
+    let mut copied = Vec::new();
+    for (sound_id, name, file_path) in external {
+        let source = PathBuf::from(&file_path);
+        if !source.exists() {
+            report.failed.push(ItemFailure {
+                entry: name,
+                reason: format!("Original file missing: {}", file_path),
+            });
+            continue;
+        }
+        match copy_into_library(&source, &sound_id) {
+            Ok(dest) => copied.push((sound_id, name, dest.to_string_lossy().to_string())),
+            Err(reason) => report.failed.push(ItemFailure { entry: name, reason }),
+        }
+    }
+
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    for (sound_id, name, new_path) in copied {
+        if let Some(sound) = audio_state.sounds.get_mut(&sound_id) {
+            sound.file_path = new_path;
+            report.migrated.push(name);
+        }
+    }
+    save_sounds(&audio_state.sounds);
+
+    Ok(report)
+}
+
+// Resolved form of a path for comparisons, so separator or casing differences in a
+// stored path can't make a referenced file look orphaned
+fn canonical_path(path: impl AsRef<std::path::Path>) -> PathBuf {
+    let path = path.as_ref();
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+struct PruneReport {
+    // Library files no sound references (deleted unless this was a dry run)
+    orphaned: Vec<String>,
+    #[serde(rename = "freedBytes")]
+    freed_bytes: u64,
+    #[serde(rename = "dryRun")]
+    dry_run: bool,
+    failed: Vec<ItemFailure>,
+}
+
+// Find files in the managed library that no sound points at and delete them, or only
+// list them when dry_run is set. Only regular files directly inside the library are
+// considered, so external files and anything reached through a symlink are never touched.
+#[tauri::command]
+fn prune_orphaned_files(dry_run: bool, state: State<AppState>) -> Result<PruneReport, String> {
+    let mut report = PruneReport {
+        dry_run,
+        ..Default::default()
+    };
+    let Some(library_dir) = get_library_dir().filter(|dir| dir.is_dir()) else {
+        return Ok(report);
+    };
+
+    let referenced: HashSet<PathBuf> = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state
+            .sounds
+            .values()
+            .map(|s| canonical_path(&s.file_path))
+            .collect()
+    };
+
+    let entries = std::fs::read_dir(&library_dir).map_err(|e| e.to_string())?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = std::fs::symlink_metadata(&path) else {
+            continue;
+        };
+        if !metadata.is_file() || referenced.contains(&canonical_path(&path)) {
+            continue;
+        }
+
+        let name = path.to_string_lossy().to_string();
+        if !dry_run {
+            if let Err(e) = std::fs::remove_file(&path) {
+                report.failed.push(ItemFailure {
+                    entry: name,
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        }
+        report.freed_bytes += metadata.len();
+        report.orphaned.push(name);
+    }
+    report.orphaned.sort();
+
+    Ok(report)
+}
+
+// Copy a pad with all its settings, useful for variants of the same file with different trims.
+// The keybind is left empty so the copy doesn't conflict with the original.
+#[tauri::command]
+fn duplicate_sound(sound_id: String, state: State<AppState>) -> Result<Sound, String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let original = audio_state
+        .sounds
+        .get(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+
+    let order = audio_state
+        .sounds
+        .values()
+        .map(|s| s.order)
+        .max()
+        .map_or(0, |max| max + 1);
+
+    let sound = Sound {
+        id: Uuid::new_v4().to_string(),
+        name: format!("{} (copy)", original.name),
+        keybind: None,
+        order,
+        created_at: unix_now_millis(),
+        ..original.clone()
+    };
+
+    audio_state.sounds.insert(sound.id.clone(), sound.clone());
+    save_sounds(&audio_state.sounds);
+
+    Ok(sound)
+}
+
+// Newest pads first; sounds from before created_at was tracked sort last
+#[tauri::command]
+fn get_recently_added(limit: usize, state: State<AppState>) -> Result<Vec<Sound>, String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    let mut sounds: Vec<Sound> = audio_state.sounds.values().cloned().collect();
+    sounds.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+    sounds.truncate(limit);
+    Ok(sounds)
+}
+
+// Sounds still waiting for a keybind, in board order
+#[tauri::command]
+fn get_unbound_sounds(state: State<AppState>) -> Result<Vec<Sound>, String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    let mut sounds: Vec<Sound> = audio_state
+        .sounds
+        .values()
+        .filter(|s| s.keybind.is_none())
+        .cloned()
+        .collect();
+    sounds.sort_by_key(|s| s.order);
+    Ok(sounds)
+}
+
+#[tauri::command]
+fn remove_sound(sound_id: String, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.sounds.remove(&sound_id);
+    save_sounds(&audio_state.sounds);
+    if audio_state.stats.sounds.remove(&sound_id).is_some() {
+        save_stats(&audio_state.stats);
+    }
+    Ok(())
+}
+
+// Remove several sounds with a single save. Keybinds are unregistered, and library
+// files are deleted once no remaining pad (e.g. a duplicate) still uses them.
+#[tauri::command]
+fn remove_sounds(app_handle: AppHandle, sound_ids: Vec<String>, state: State<AppState>) -> Result<usize, String> {
+    let mut keybinds = Vec::new();
+    let mut removed_files = Vec::new();
+    let removed = {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        let mut removed = 0;
+        for sound_id in &sound_ids {
+            if let Some(sound) = audio_state.sounds.remove(sound_id) {
+                keybinds.extend(sound.keybind);
+                removed_files.push(sound.file_path);
+                audio_state.stats.sounds.remove(sound_id);
+                removed += 1;
+            }
+        }
+
+        removed_files.retain(|path| {
+            is_in_library(path) && !audio_state.sounds.values().any(|s| &s.file_path == path)
+        });
+
+        // Close the gaps left in the order
+        let mut remaining: Vec<&mut Sound> = audio_state.sounds.values_mut().collect();
+        remaining.sort_by_key(|s| s.order);
+        for (index, sound) in remaining.into_iter().enumerate() {
+            sound.order = index as i32;
+        }
+
+        save_sounds(&audio_state.sounds);
+        save_stats(&audio_state.stats);
+        removed
+    };
+
+    // The sounds are already gone from the saved board, so finish the cleanup before
+    // reporting any keybind that couldn't be released
+    let mut errors = Vec::new();
+    for keybind in keybinds {
+        if let Err(e) = unregister_sound_keybind(app_handle.clone(), keybind.clone()) {
+            errors.push(format!("{}: {}", keybind, e));
+        }
+    }
+    removed_files.sort();
+    removed_files.dedup();
+    for path in removed_files {
+        let _ = std::fs::remove_file(path);
+    }
+
+    if !errors.is_empty() {
+        return Err(format!(
+            "Removed {} sounds, but some keybinds couldn't be unregistered: {}",
+            removed,
+            errors.join(", ")
+        ));
+    }
+    Ok(removed)
+}
+
+#[tauri::command]
+fn update_sound_keybind(
+    sound_id: String,
+    keybind: Option<String>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    if let Some(ref keybind) = keybind {
+        let ignore = HashSet::from([sound_id.clone()]);
+        if let Some(owner) = find_keybind_conflict(&audio_state, keybind, &ignore) {
+            return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+        }
+    }
+    if let Some(sound) = audio_state.sounds.get_mut(&sound_id) {
+        sound.keybind = keybind;
+    }
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[tauri::command]
+fn update_sound_trim(
+    sound_id: String,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    if let Some(sound) = audio_state.sounds.get_mut(&sound_id) {
+        sound.start_time = start_time;
+        sound.end_time = end_time;
+        // Seconds-based edits replace any sample-accurate trim
+        sound.start_sample = None;
+        sound.end_sample = None;
+    }
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+struct TrimOffsetReport {
+    adjusted: Vec<String>,
+    skipped: Vec<ItemFailure>,
+}
+
+// Shift the trim of several sounds at once, e.g. to cut the same lead-in from a whole batch.
+// An end delta on a sound with no end trim counts from the end of the file. Sounds whose
+// window would end up empty are skipped and reported.
+#[tauri::command]
+fn apply_trim_offset(
+    sound_ids: Vec<String>,
+    start_delta: f64,
+    end_delta: f64,
+    state: State<AppState>,
+) -> Result<TrimOffsetReport, String> {
+    let paths: Vec<(String, Option<String>)> = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        sound_ids
+            .iter()
+            .map(|id| (id.clone(), audio_state.sounds.get(id).map(|s| s.file_path.clone())))
+            .collect()
+    };
+    // Probing durations can be slow, so it's done without holding the state lock
+    let durations: HashMap<String, f64> = paths
+        .into_iter()
+        .filter_map(|(id, path)| Some((id, file_duration_secs(&path?)?)))
+        .collect();
+
+    let mut report = TrimOffsetReport::default();
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    for sound_id in sound_ids {
+        let Some(sound) = audio_state.sounds.get_mut(&sound_id) else {
+            report.skipped.push(ItemFailure {
+                entry: sound_id,
+                reason: "Sound not found".to_string(),
+            });
+            continue;
+        };
+        let duration = durations.get(&sound_id).copied();
+
+        let start = (sound.start_time.unwrap_or(0.0) + start_delta).max(0.0);
+        let end = match sound.end_time {
+            Some(end) => Some(end + end_delta),
+            None if end_delta != 0.0 => duration.map(|d| d + end_delta),
+            None => None,
+        }
+        .map(|end| duration.map_or(end, |d| end.min(d)));
+
+        if let Some(window_end) = end.or(duration).filter(|window_end| *window_end <= start) {
+            report.skipped.push(ItemFailure {
+                entry: sound.name.clone(),
+                reason: format!("Trim window would be empty ({:.2}s to {:.2}s)", start, window_end),
+            });
+            continue;
+        }
+
+        sound.start_time = (start > 0.0).then_some(start);
+        sound.end_time = end;
+        // Seconds-based edits replace any sample-accurate trim
+        sound.start_sample = None;
+        sound.end_sample = None;
+        report.adjusted.push(sound_id);
+    }
+
+    if !report.adjusted.is_empty() {
+        save_sounds(&audio_state.sounds);
+    }
+    Ok(report)
+}
+
+// Sample-accurate trim in frames (one sample per channel), for precise edits
+#[tauri::command]
+fn update_sound_trim_samples(
+    sound_id: String,
+    start_sample: Option<u64>,
+    end_sample: Option<u64>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    if let (Some(start), Some(end)) = (start_sample, end_sample) {
+        if start >= end {
+            return Err("Start sample must be before end sample".to_string());
+        }
+    }
+
+    let file_path = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state
+            .sounds
+            .get(&sound_id)
+            .map(|s| s.file_path.clone())
+            .ok_or_else(|| "Sound not found".to_string())?
+    };
+    let sample_rate = open_decoder(&file_path).map_err(|e| e.to_string())?.sample_rate().max(1) as f64;
+
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    if let Some(sound) = audio_state.sounds.get_mut(&sound_id) {
+        sound.start_sample = start_sample;
+        sound.end_sample = end_sample;
+        // Mirror into seconds so the simple trim UI still shows the window
+        sound.start_time = start_sample.map(|frame| frame as f64 / sample_rate);
+        sound.end_time = end_sample.map(|frame| frame as f64 / sample_rate);
+    }
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+fn update_sound_settings(
+    sound_id: String,
+    volume: f32,
+    loop_mode: bool,
+    playback_speed: f32,
+    echo_delay: f32,
+    echo_volume: f32,
+    reverb_decay: f32,
+    bass_boost: f32,
+    fake_bass_boost: f32,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    if let Some(sound) = audio_state.sounds.get_mut(&sound_id) {
+        sound.volume = volume.clamp(0.0, 2.0); // Allow up to 200%
+        sound.loop_mode = loop_mode;
+        sound.playback_speed = playback_speed.clamp(0.25, 2.0); // 0.25x to 2x speed
+        sound.echo_delay = echo_delay.clamp(0.0, 1.0); // 0 to 1 second delay
+        sound.echo_volume = echo_volume.clamp(0.0, 1.0); // Echo volume 0-100%
+        sound.reverb_decay = reverb_decay.clamp(0.0, 0.9); // Reverb decay 0-90%
+        sound.bass_boost = bass_boost.clamp(0.0, 3.0); // Bass boost 0-300%
+        sound.fake_bass_boost = fake_bass_boost.clamp(0.0, 10.0); // Fake bass boost 0-1000% (extreme!)
+    }
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct TrimPatch {
+    #[serde(rename = "startTime", default)]
+    start_time: Option<f64>,
+    #[serde(rename = "endTime", default)]
+    end_time: Option<f64>,
+}
+
+// Fields to change on a sound; absent fields are left alone. For keybind, color and group an
+// empty string clears the value.
+#[derive(Debug, Clone, Deserialize)]
+struct SoundPatch {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    keybind: Option<String>,
+    #[serde(default)]
+    volume: Option<f32>,
+    // Replaces both ends, like update_sound_trim
+    #[serde(default)]
+    trim: Option<TrimPatch>,
+    #[serde(rename = "playbackSpeed", default)]
+    playback_speed: Option<f32>,
+    #[serde(default)]
+    color: Option<String>,
+    #[serde(default)]
+    group: Option<String>,
+    #[serde(rename = "loopMode", default)]
+    loop_mode: Option<bool>,
+}
+
+// Apply several edits to one sound in a single locked pass with one save, swapping the
+// keybind registration if it changed. Nothing is applied if any field is rejected.
+#[tauri::command]
+fn update_sound(app_handle: AppHandle, sound_id: String, patch: SoundPatch, state: State<AppState>) -> Result<Sound, String> {
+    let (sound, keybind_change) = {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        if !audio_state.sounds.contains_key(&sound_id) {
+            return Err("Sound not found".to_string());
+        }
+
+        let name = patch.name.map(|n| n.trim().to_string());
+        if name.as_deref().is_some_and(str::is_empty) {
+            return Err("Sound name cannot be empty".to_string());
+        }
+        // Validated up front so a bad bind doesn't leave the other fields half-applied
+        let keybind = match patch.keybind.as_deref().map(str::trim) {
+            Some("") => Some(None),
+            Some(keybind) => {
+                let keybind = validate_keybind(keybind)?;
+                let ignore = HashSet::from([sound_id.clone()]);
+                if let Some(owner) = find_keybind_conflict(&audio_state, &keybind, &ignore) {
+                    return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+                }
+                Some(Some(keybind))
+            }
+            None => None,
+        };
+
+        let sound = audio_state
+            .sounds
+            .get_mut(&sound_id)
+            .ok_or_else(|| "Sound not found".to_string())?;
+        if let Some(name) = name {
+            sound.name = name;
+        }
+        let mut keybind_change = None;
+        if let Some(keybind) = keybind {
+            if keybind != sound.keybind {
+                keybind_change = Some((std::mem::replace(&mut sound.keybind, keybind.clone()), keybind));
+            }
+        }
+        if let Some(volume) = patch.volume {
+            sound.volume = volume.clamp(0.0, 2.0); // Same range as update_sound_settings
+        }
+        if let Some(trim) = patch.trim {
+            sound.start_time = trim.start_time;
+            sound.end_time = trim.end_time;
+            // Seconds-based edits replace any sample-accurate trim
+            sound.start_sample = None;
+            sound.end_sample = None;
+        }
+        if let Some(speed) = patch.playback_speed {
+            sound.playback_speed = speed.clamp(0.25, 2.0);
+        }
+        if let Some(color) = patch.color {
+            sound.color = Some(color).filter(|c| !c.trim().is_empty());
+        }
+        if let Some(group) = patch.group {
+            sound.group = Some(group.trim().to_string()).filter(|g| !g.is_empty());
+        }
+        if let Some(loop_mode) = patch.loop_mode {
+            sound.loop_mode = loop_mode;
+        }
+
+        let sound = sound.clone();
+        save_sounds(&audio_state.sounds);
+        (sound, keybind_change)
+    };
+
+    if let Some((old, new)) = keybind_change {
+        if let Some(old) = old {
+            unregister_sound_keybind(app_handle.clone(), old)?;
+        }
+        if let Some(new) = new {
+            // A combo the OS refuses still works through the rdev hook
+            let _ = register_sound_shortcut(&app_handle, &sound_id, &new)?;
+        }
+    }
+    Ok(sound)
+}
+
+#[tauri::command]
+fn set_sound_exclusive_group(
+    sound_id: String,
+    group: Option<String>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get_mut(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+    // Treat an empty name as clearing the group
+    sound.exclusive_group = group.filter(|g| !g.trim().is_empty());
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_sound_random_start(sound_id: String, enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get_mut(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+    sound.random_start = enabled;
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_sound_group(sound_id: String, group: Option<String>, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get_mut(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+    sound.group = group
+        .map(|g| g.trim().to_string())
+        .filter(|g| !g.is_empty());
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+// Rename a group on every sound in it. Renaming onto an existing group merges the
+// two, which must be confirmed with `merge` so groups aren't combined by accident.
+#[tauri::command]
+fn rename_group(
+    app_handle: AppHandle,
+    old_name: String,
+    new_name: String,
+    merge: bool,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let new_name = new_name.trim().to_string();
+    if new_name.is_empty() {
+        return Err("Group name cannot be empty".to_string());
+    }
+    if new_name == old_name {
+        return Ok(());
+    }
+
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let target_exists = audio_state
+        .sounds
+        .values()
+        .any(|s| s.group.as_deref() == Some(new_name.as_str()));
+    if target_exists && !merge {
+        return Err(format!("Group '{}' already exists, confirm to merge", new_name));
+    }
+
+    let (step_bind, dropped_step_bind) = move_group_bind(&mut audio_state.group_step_keybinds, &old_name, &new_name);
+    let (show_bind, dropped_show_bind) = move_group_bind(&mut audio_state.group_show_keybinds, &old_name, &new_name);
+
+    for sound in audio_state.sounds.values_mut() {
+        if sound.group.as_deref() == Some(old_name.as_str()) {
+            sound.group = Some(new_name.clone());
+        }
+    }
+
+    // Keep the display order: a merged group keeps the target's position
+    if target_exists || audio_state.group_order.contains(&new_name) {
+        audio_state.group_order.retain(|g| g != &old_name);
+    } else if let Some(entry) = audio_state.group_order.iter_mut().find(|g| **g == old_name) {
+        *entry = new_name.clone();
+    }
+
+    save_sounds(&audio_state.sounds);
+    save_settings(&audio_state);
+    drop(audio_state);
+
+    // A merged group starts over, otherwise it carries on where it was
+    if let Ok(mut cursors) = GROUP_CURSORS.lock() {
+        if let Some(cursor) = cursors.remove(&old_name) {
+            if !target_exists {
+                cursors.insert(new_name.clone(), cursor);
+            }
+        }
+    }
+
+    for keybind in [dropped_step_bind, dropped_show_bind].into_iter().flatten() {
+        unregister_sound_keybind(app_handle.clone(), keybind)?;
+    }
+    if let Some(keybind) = step_bind {
+        register_group_step_shortcut(&app_handle, &new_name, &keybind)?;
+    }
+    if let Some(keybind) = show_bind {
+        register_group_show_shortcut(&app_handle, &new_name, &keybind)?;
+    }
+    Ok(())
+}
+
+// Move a group's bind to the group's new name. When the target already has a bind of its own
+// that one wins. Returns the bind to register under the new name and the one to release.
+fn move_group_bind(binds: &mut HashMap<String, String>, old_name: &str, new_name: &str) -> (Option<String>, Option<String>) {
+    let Some(keybind) = binds.remove(old_name) else {
+        return (None, None);
+    };
+    if binds.contains_key(new_name) {
+        return (None, Some(keybind));
+    }
+    binds.insert(new_name.to_string(), keybind.clone());
+    (Some(keybind), None)
+}
+
+// Display order for group sections, groups not listed render after these
+#[tauri::command]
+fn set_group_order(ordered_groups: Vec<String>, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let mut group_order: Vec<String> = Vec::new();
+    for group in ordered_groups {
+        if !group.is_empty() && !group_order.contains(&group) {
+            group_order.push(group);
+        }
+    }
+    audio_state.group_order = group_order;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Play the group member under the cursor and advance it, wrapping after the last.
+// Members go by pad order. Returns the id of the sound that was started.
+fn step_group(group: &str, state: &State<AppState>, engine: &State<Engine>) -> Result<String, String> {
+    let sound_id = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        let mut members: Vec<&Sound> = audio_state
+            .sounds
+            .values()
+            .filter(|s| s.group.as_deref() == Some(group))
+            .collect();
+        if members.is_empty() {
+            return Err(format!("Group '{}' has no sounds", group));
+        }
+        members.sort_by_key(|s| s.order);
+
+        let mut cursors = GROUP_CURSORS.lock().map_err(|e| e.to_string())?;
+        let cursor = cursors.entry(group.to_string()).or_insert(0);
+        let index = *cursor % members.len();
+        *cursor = index + 1;
+        members[index].id.clone()
+    };
+    start_sound(sound_id.clone(), None, false, state, engine)?;
+    Ok(sound_id)
+}
+
+#[tauri::command]
+fn play_next_in_group(group: String, state: State<AppState>, engine: State<Engine>) -> Result<String, String> {
+    step_group(&group, &state, &engine)
+}
+
+// Start the group over from its first member on the next step
+#[tauri::command]
+fn reset_group_cursor(group: String) -> Result<(), String> {
+    GROUP_CURSORS.lock().map_err(|e| e.to_string())?.remove(&group);
+    Ok(())
+}
+
+// Set (or clear) the keybind that steps through a group
+#[tauri::command]
+fn set_group_step_keybind(
+    app_handle: AppHandle,
+    group: String,
+    keybind: Option<String>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let old = {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        if let Some(ref keybind) = keybind {
+            let ignore = HashSet::from([format!("{}{}", GROUP_NEXT_PREFIX, group)]);
+            if let Some(owner) = find_keybind_conflict(&audio_state, keybind, &ignore) {
+                return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+            }
+        }
+        let old = match keybind {
+            Some(ref keybind) => audio_state.group_step_keybinds.insert(group.clone(), keybind.clone()),
+            None => audio_state.group_step_keybinds.remove(&group),
+        };
+        save_settings(&audio_state);
+        old
+    };
+
+    if let Some(old) = old {
+        unregister_sound_keybind(app_handle.clone(), old)?;
+    }
+    if let Some(keybind) = keybind {
+        register_group_step_shortcut(&app_handle, &group, &keybind)?;
+    }
+    Ok(())
+}
+
+// Set (or clear) the keybind that jumps to a group's view
+#[tauri::command]
+fn set_group_show_keybind(
+    app_handle: AppHandle,
+    group: String,
+    keybind: Option<String>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let old = {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        if let Some(ref keybind) = keybind {
+            let ignore = HashSet::from([format!("{}{}", SHOW_GROUP_PREFIX, group)]);
+            if let Some(owner) = find_keybind_conflict(&audio_state, keybind, &ignore) {
+                return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+            }
+        }
+        let old = match keybind {
+            Some(ref keybind) => audio_state.group_show_keybinds.insert(group.clone(), keybind.clone()),
+            None => audio_state.group_show_keybinds.remove(&group),
+        };
+        save_settings(&audio_state);
+        old
+    };
+
+    if let Some(old) = old {
+        unregister_sound_keybind(app_handle.clone(), old)?;
+    }
+    if let Some(keybind) = keybind {
+        register_group_show_shortcut(&app_handle, &group, &keybind)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn update_sound_order(sound_ids: Vec<String>, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    for (index, sound_id) in sound_ids.iter().enumerate() {
+        if let Some(sound) = audio_state.sounds.get_mut(sound_id) {
+            sound.order = index as i32;
+        }
+    }
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+enum SortCriteria {
+    Name,
+    PlayCount,
+    RecentlyAdded,
+    Group,
+}
+
+// One-shot reorder of the whole board. Ties keep their current relative order.
+#[tauri::command]
+fn sort_sounds(criteria: SortCriteria, state: State<AppState>) -> Result<Vec<Sound>, String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let mut sounds: Vec<Sound> = audio_state.sounds.values().cloned().collect();
+    sounds.sort_by_key(|s| s.order);
+
+    match criteria {
+        SortCriteria::Name => sounds.sort_by_key(|s| s.name.to_lowercase()),
+        SortCriteria::PlayCount => {
+            let plays = |s: &Sound| audio_state.stats.sounds.get(&s.id).map_or(0, |stats| stats.all_time_count);
+            sounds.sort_by_key(|s| std::cmp::Reverse(plays(s)));
+        }
+        SortCriteria::RecentlyAdded => sounds.sort_by_key(|s| std::cmp::Reverse(s.created_at)),
+        // Sections in their display order, then any unlisted groups by name, ungrouped last
+        SortCriteria::Group => {
+            let group_order = &audio_state.group_order;
+            sounds.sort_by_key(|s| match s.group.as_deref() {
+                Some(group) => match group_order.iter().position(|g| g == group) {
+                    Some(position) => (0, position, String::new()),
+                    None => (1, 0, group.to_lowercase()),
+                },
+                None => (2, 0, String::new()),
+            });
+        }
+    }
+
+    for (index, sound) in sounds.iter_mut().enumerate() {
+        sound.order = index as i32;
+        if let Some(stored) = audio_state.sounds.get_mut(&sound.id) {
+            stored.order = index as i32;
+        }
+    }
+    save_sounds(&audio_state.sounds);
+    Ok(sounds)
+}
+
+// Warm up audio device by playing a very short silent tone
+// This initializes the audio pipeline and prevents first-play issues
+fn warmup_audio_device(device_name: Option<&str>) {
+    std::thread::spawn({
+        let device = device_name.map(|s| s.to_string());
+        move || {
+            let (_stream, stream_handle): (OutputStream, OutputStreamHandle) = if let Some(ref name) = device {
+                if let Some(dev) = find_device_by_name(name) {
+                    match OutputStream::try_from_device(&dev) {
+                        Ok(s) => s,
+                        Err(_) => return,
+                    }
+                } else {
+                    return;
+                }
+            } else {
+                return;
+            };
+
+            if let Ok(sink) = Sink::try_new(&stream_handle) {
+                // Play a very short, very quiet sine wave to initialize the device
+                let source = SineWave::new(440.0)
+                    .take_duration(std::time::Duration::from_millis(50))
+                    .amplify(0.001); // Nearly silent
+                sink.append(source);
+                sink.sleep_until_end();
+            }
+        }
+    });
+}
+
+// Queued plays older than this are dropped rather than played late when the device returns
+const PENDING_PLAY_MAX_AGE: std::time::Duration = std::time::Duration::from_secs(30);
+const MAX_PENDING_PLAYS: usize = 8;
+
+impl AudioEngine {
+    fn primary_unavailable(&self) -> Option<String> {
+        self.primary_status.lock().ok().and_then(|s| s.unavailable.clone())
+    }
+
+    // Whether a play aimed at `device` should be held back because it's the missing primary.
+    // Under FallbackDefault it goes ahead (the stream opens on the default) with one warning.
+    fn hold_for_missing_primary(&self, plan: &PlaybackPlan, device: Option<&str>) -> bool {
+        let behavior = self.missing_device_behavior.lock().map(|b| *b).unwrap_or_default();
+        let Ok(mut status) = self.primary_status.lock() else {
+            return false;
+        };
+        let Some(missing) = status.unavailable.clone() else {
+            return false;
+        };
+        if !device.is_some_and(|d| d.eq_ignore_ascii_case(&missing)) {
+            return false;
+        }
+
+        match behavior {
+            MissingDeviceBehavior::FallbackDefault => {
+                if !std::mem::replace(&mut status.fallback_warned, true) {
+                    eprintln!("[Playback] Primary device '{}' is missing, playing on the default output", missing);
+                    if let Some(app_handle) = APP_HANDLE.get() {
+                        let _ = app_handle.emit_all("device-fallback", &missing);
+                    }
+                }
+                false
+            }
+            MissingDeviceBehavior::Silence => true,
+            MissingDeviceBehavior::Queue => {
+                status.pending.retain(|(at, _)| at.elapsed() < PENDING_PLAY_MAX_AGE);
+                if status.pending.len() < MAX_PENDING_PLAYS {
+                    status.pending.push((std::time::Instant::now(), plan.clone()));
+                }
+                true
+            }
+        }
+    }
+
+    // Record whether the primary device can be found, telling the UI when that changes.
+    // Plays queued while it was gone go out once it's back.
+    fn set_primary_unavailable(self: &Arc<Self>, primary: Option<&str>, found: bool) {
+        let (event, device, pending) = {
+            let Ok(mut status) = self.primary_status.lock() else {
+                return;
+            };
+            match (primary, &status.unavailable) {
+                (Some(name), None) if !found => {
+                    *status = PrimaryDeviceStatus {
+                        unavailable: Some(name.to_string()),
+                        ..Default::default()
+                    };
+                    ("device-unavailable", name.to_string(), Vec::new())
+                }
+                (_, Some(missing)) if found || primary != Some(missing.as_str()) => {
+                    let missing = missing.clone();
+                    let pending = std::mem::take(&mut *status).pending;
+                    // A different primary was picked meanwhile, so the queue no longer applies
+                    if primary != Some(missing.as_str()) {
+                        return;
+                    }
+                    ("device-available", missing, pending)
+                }
+                _ => return,
+            }
+        };
+
+        eprintln!("[Devices] {}: {}", event, device);
+        if let Some(app_handle) = APP_HANDLE.get() {
+            let _ = app_handle.emit_all(event, &device);
+        }
+        let targets = [Some(device)];
+        for (at, plan) in pending {
+            if at.elapsed() < PENDING_PLAY_MAX_AGE {
+                self.queue_playback(&plan, &targets);
+            }
+        }
+    }
+}
+
+// Poll for the saved primary device coming and going (cpal has no hotplug callback)
+fn start_device_watcher() {
+    std::thread::spawn(|| loop {
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        let (Some(app_handle), Some(engine)) = (APP_HANDLE.get(), audio_engine()) else {
+            continue;
+        };
+        let state: State<AppState> = app_handle.state();
+        let Some(primary) = state.lock().ok().map(|s| s.primary_device.clone()) else {
+            continue;
+        };
+        let found = primary.as_deref().is_none_or(|name| find_device_by_name(name).is_some());
+        engine.set_primary_unavailable(primary.as_deref(), found);
+    });
+}
+
+// An exact (case-insensitive) name match wins, otherwise the first device whose name contains
+// the requested one. A loose match is reported so mis-routing doesn't go unnoticed.
+fn find_device_by_name(name: &str) -> Option<rodio::cpal::Device> {
+    use rodio::cpal::traits::{HostTrait, DeviceTrait};
+
+    let host = rodio::cpal::default_host();
+    let name_lower = name.to_lowercase();
+
+    let mut loose = None;
+    for device in host.output_devices().ok()? {
+        let Ok(device_name) = device.name() else {
+            continue;
+        };
+        if device_name.eq_ignore_ascii_case(name) {
+            note_device_resolution(name, Some(device_name));
+            return Some(device);
+        }
+        if loose.is_none() && device_name.to_lowercase().contains(&name_lower) {
+            loose = Some((device, device_name));
+        }
+    }
+    let (device, device_name) = loose?;
+    note_device_resolution(name, Some(device_name));
+    Some(device)
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DeviceResolution {
+    requested: String,
+    // What was actually opened, None when not even the default output could be named
+    resolved: Option<String>,
+}
+
+lazy_static::lazy_static! {
+    // Requested device names that currently resolve to some other device
+    static ref DEVICE_RESOLUTIONS: Mutex<HashMap<String, Option<String>>> = Mutex::new(HashMap::new());
+}
+
+// Record which device a requested name ended up on. Logged and sent as "device-resolution"
+// only when it starts differing or changes, not on every play.
+fn note_device_resolution(requested: &str, resolved: Option<String>) {
+    let Ok(mut resolutions) = DEVICE_RESOLUTIONS.lock() else {
+        return;
+    };
+    if resolved.as_deref().is_some_and(|r| r.eq_ignore_ascii_case(requested)) {
+        resolutions.remove(requested);
+        return;
+    }
+    if resolutions.insert(requested.to_string(), resolved.clone()) == Some(resolved.clone()) {
+        return;
+    }
+    drop(resolutions);
+
+    eprintln!(
+        "[Devices] '{}' not found exactly, using '{}'",
+        requested,
+        resolved.as_deref().unwrap_or("default output")
+    );
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let _ = app_handle.emit_all("device-resolution", DeviceResolution {
+            requested: requested.to_string(),
+            resolved,
+        });
+    }
+}
+
+fn device_resolutions() -> Vec<DeviceResolution> {
+    let mut resolutions: Vec<DeviceResolution> = DEVICE_RESOLUTIONS
+        .lock()
+        .map(|r| {
+            r.iter()
+                .map(|(requested, resolved)| DeviceResolution {
+                    requested: requested.clone(),
+                    resolved: resolved.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    resolutions.sort_by(|a, b| a.requested.cmp(&b.requested));
+    resolutions
+}
+
+// Limit on opening + probing a file, mirrored from settings (0 = wait forever)
+static FILE_OPEN_TIMEOUT_MS: AtomicU64 = AtomicU64::new(5000);
+const MAX_FILE_OPEN_TIMEOUT_MS: u32 = 60_000;
+// Below this ordinary local opens start timing out and get their path marked as stalled
+const MIN_FILE_OPEN_TIMEOUT_MS: u32 = 500;
+
+// 0 (no timeout) or within the allowed range
+fn clamp_file_open_timeout(ms: u32) -> u32 {
+    if ms == 0 {
+        0
+    } else {
+        ms.clamp(MIN_FILE_OPEN_TIMEOUT_MS, MAX_FILE_OPEN_TIMEOUT_MS)
+    }
+}
+
+fn default_file_open_timeout_ms() -> u32 {
+    5000
+}
+
+// Most abandoned opens allowed to still be blocked at once
+const MAX_STALLED_OPENS: usize = 4;
+
+lazy_static::lazy_static! {
+    // Paths whose open timed out and is still blocked on its thread
+    static ref STALLED_OPENS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+// Open and decode an audio file for playback. A file on a stalled network share can block
+// in open/probe for a long time, so that runs on its own thread and is abandoned after the
+// timeout instead of tying up a playback worker. A path stays refused until its abandoned
+// open returns, and only a few may be stuck at once, so a dead share can't pile up threads.
+fn open_decoder(file_path: &str) -> Result<AudioDecoder, PlaybackError> {
+    let timeout_ms = FILE_OPEN_TIMEOUT_MS.load(Ordering::SeqCst);
+    if timeout_ms == 0 {
+        return open_decoder_blocking(file_path);
+    }
+
+    {
+        let stalled = STALLED_OPENS.lock().map_err(|e| PlaybackError::File(e.to_string()))?;
+        if stalled.contains(file_path) {
+            return Err(PlaybackError::FileTimeout(format!(
+                "Still waiting on an earlier open of '{}'",
+                file_path
+            )));
+        }
+        if stalled.len() >= MAX_STALLED_OPENS {
+            return Err(PlaybackError::FileTimeout(format!(
+                "Not opening '{}' while {} other files are stuck opening",
+                file_path,
+                stalled.len()
+            )));
+        }
+    }
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let path = file_path.to_string();
+    std::thread::spawn(move || {
+        let _ = tx.send(open_decoder_blocking(&path));
+        if let Ok(mut stalled) = STALLED_OPENS.lock() {
+            stalled.remove(&path);
+        }
+    });
+    if let Ok(result) = rx.recv_timeout(std::time::Duration::from_millis(timeout_ms)) {
+        return result;
+    }
+
+    // Checked again under the lock: the opener sends before it clears the path, so either
+    // its result is here or the path is marked before it can be cleared
+    let mut stalled = STALLED_OPENS.lock().map_err(|e| PlaybackError::File(e.to_string()))?;
+    if let Ok(result) = rx.try_recv() {
+        return result;
+    }
+    stalled.insert(file_path.to_string());
+    Err(PlaybackError::FileTimeout(format!(
+        "Opening '{}' took longer than {}ms",
+        file_path, timeout_ms
+    )))
+}
+
+fn open_decoder_blocking(file_path: &str) -> Result<AudioDecoder, PlaybackError> {
+    let extension = std::path::Path::new(file_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    if extension == "opus" {
+        return OpusSource::open(file_path).map(|opus| AudioDecoder::Opus(Box::new(opus)));
+    }
+
+    let file = File::open(file_path).map_err(|e| PlaybackError::File(format!("Failed to open file: {}", e)))?;
+    match Decoder::new(BufReader::new(file)) {
+        Ok(decoder) => Ok(AudioDecoder::Rodio(Box::new(decoder))),
+        // Ogg and WebM files can hold Opus too, which rodio gives up on
+        Err(e) if matches!(extension.as_str(), "ogg" | "webm" | "mka") => OpusSource::open(file_path)
+            .map(|opus| AudioDecoder::Opus(Box::new(opus)))
+            .map_err(|_| PlaybackError::Decode(format!("Failed to decode audio: {}", e))),
+        Err(e) => Err(PlaybackError::Decode(format!("Failed to decode audio: {}", e))),
+    }
+}
+
+// A decoded audio file: rodio's decoders, or Opus which rodio can't decode
+enum AudioDecoder {
+    Rodio(Box<Decoder<BufReader<File>>>),
+    Opus(Box<OpusSource>),
+}
+
+impl Iterator for AudioDecoder {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        match self {
+            AudioDecoder::Rodio(decoder) => decoder.next(),
+            AudioDecoder::Opus(decoder) => decoder.next(),
+        }
+    }
+}
+
+impl Source for AudioDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        match self {
+            AudioDecoder::Rodio(decoder) => decoder.current_frame_len(),
+            AudioDecoder::Opus(decoder) => decoder.current_frame_len(),
+        }
+    }
+
+    fn channels(&self) -> u16 {
+        match self {
+            AudioDecoder::Rodio(decoder) => decoder.channels(),
+            AudioDecoder::Opus(decoder) => decoder.channels(),
+        }
+    }
+
+    fn sample_rate(&self) -> u32 {
+        match self {
+            AudioDecoder::Rodio(decoder) => decoder.sample_rate(),
+            AudioDecoder::Opus(decoder) => decoder.sample_rate(),
+        }
+    }
+
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        match self {
+            AudioDecoder::Rodio(decoder) => decoder.total_duration(),
+            AudioDecoder::Opus(decoder) => decoder.total_duration(),
+        }
+    }
+}
+
+// Opus always decodes at 48 kHz, whatever input rate the header mentions
+const OPUS_SAMPLE_RATE: u32 = 48_000;
+
+// Largest Opus packet, 120ms at 48 kHz, in samples per channel
+const OPUS_MAX_PACKET_FRAMES: usize = 5760;
+
+// Compressed Opus packets, read from the file as playback reaches them
+enum OpusPackets {
+    Ogg {
+        reader: ogg::PacketReader<BufReader<File>>,
+        serial: u32,
+    },
+    Matroska {
+        reader: Box<symphonia::default::formats::MkvReader>,
+        track_id: u32,
+    },
+}
+
+impl OpusPackets {
+    // The next packet of the Opus track; a damaged tail just ends the stream early
+    fn next_packet(&mut self) -> Option<Vec<u8>> {
+        use symphonia::core::formats::FormatReader;
+
+        match self {
+            OpusPackets::Ogg { reader, serial } => loop {
+                let Ok(Some(packet)) = reader.read_packet() else {
+                    return None;
+                };
+                if packet.stream_serial() == *serial {
+                    return Some(packet.data);
+                }
+            },
+            OpusPackets::Matroska { reader, track_id } => loop {
+                let packet = reader.next_packet().ok()?;
+                if packet.track_id() == *track_id {
+                    return Some(packet.data.into_vec());
+                }
+            },
+        }
+    }
+
+    // Samples per channel in the rest of the stream, for files that don't record their length
+    fn count_frames(mut self) -> u64 {
+        std::iter::from_fn(|| self.next_packet()).map(|p| opus_packet_frames(&p)).sum()
+    }
+}
+
+// The start of an Opus track in its container
+struct OpusStream {
+    // OpusHead identification header
+    head: Vec<u8>,
+    packets: OpusPackets,
+    // Where the track ends, in samples per channel from the start including the pre-skip
+    end_granule: u64,
+}
+
+// Encoder delay from an OpusHead header, in samples per channel
+fn opus_pre_skip(head: &[u8]) -> u64 {
+    head.get(10..12).map_or(0, |b| u64::from(u16::from_le_bytes([b[0], b[1]])))
+}
+
+fn open_ogg_packets(file_path: &str) -> Result<(ogg::PacketReader<BufReader<File>>, ogg::Packet), String> {
+    let file = File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let mut reader = ogg::PacketReader::new(BufReader::new(file));
+    let head = reader
+        .read_packet()
+        .map_err(|e| format!("Failed to read Ogg stream: {}", e))?
+        .ok_or("Empty Ogg stream")?;
+    Ok((reader, head))
+}
+
+fn ogg_opus_stream(file_path: &str) -> Result<OpusStream, String> {
+    let (reader, head) = open_ogg_packets(file_path)?;
+    if !head.data.starts_with(b"OpusHead") {
+        return Err("Not an Opus stream".to_string());
+    }
+    let serial = head.stream_serial();
+    let mut packets = OpusPackets::Ogg { reader, serial };
+    // The packet after OpusHead is OpusTags
+    packets.next_packet();
+
+    let end_granule = match File::open(file_path).ok().and_then(|mut file| ogg_end_granule(&mut file, serial)) {
+        Some(granule) => granule,
+        None => {
+            let (reader, _) = open_ogg_packets(file_path)?;
+            OpusPackets::Ogg { reader, serial }.count_frames()
+        }
+    };
+    Ok(OpusStream { head: head.data, packets, end_granule })
+}
+
+// Granule position of the last page of an Ogg stream, read from the end of the file instead
+// of walking every page
+fn ogg_end_granule(file: &mut File, serial: u32) -> Option<u64> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    // Pages are at most 65307 bytes, so the last one starts within this much of the end
+    const TAIL_BYTES: u64 = 65_536;
+    let len = file.metadata().ok()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(TAIL_BYTES))).ok()?;
+    let mut tail = Vec::new();
+    file.read_to_end(&mut tail).ok()?;
+    (0..tail.len().saturating_sub(27)).rev().find_map(|at| {
+        let page = &tail[at..];
+        if !page.starts_with(b"OggS") || page[14..18] != serial.to_le_bytes() {
+            return None;
+        }
+        // -1 marks a page where no packet ends
+        let granule = u64::from_le_bytes(page[6..14].try_into().ok()?);
+        (granule != u64::MAX).then_some(granule)
+    })
+}
+
+fn open_mkv_reader(file_path: &str) -> Result<symphonia::default::formats::MkvReader, String> {
+    use symphonia::core::formats::{FormatOptions, FormatReader};
+    use symphonia::core::io::MediaSourceStream;
+
+    let file = File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let source = MediaSourceStream::new(Box::new(file), Default::default());
+    symphonia::default::formats::MkvReader::try_new(source, &FormatOptions::default())
+        .map_err(|e| format!("Failed to read container: {}", e))
+}
+
+fn matroska_opus_stream(file_path: &str) -> Result<OpusStream, String> {
+    use symphonia::core::codecs::CODEC_TYPE_OPUS;
+    use symphonia::core::formats::FormatReader;
+
+    let reader = open_mkv_reader(file_path)?;
+    let track = reader
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec == CODEC_TYPE_OPUS)
+        .ok_or("No Opus audio in this file")?;
+    let track_id = track.id;
+    let head = track.codec_params.extra_data.clone().ok_or("Opus track has no header")?.into_vec();
+
+    // The segment duration, when the muxer wrote one, is the playable length
+    let duration = track
+        .codec_params
+        .time_base
+        .zip(track.codec_params.n_frames)
+        .map(|(time_base, n_frames)| time_base.calc_time(n_frames));
+    let end_granule = match duration {
+        Some(time) => {
+            let secs = time.seconds as f64 + time.frac;
+            (secs * f64::from(OPUS_SAMPLE_RATE)).round() as u64 + opus_pre_skip(&head)
+        }
+        None => OpusPackets::Matroska { reader: Box::new(open_mkv_reader(file_path)?), track_id }.count_frames(),
+    };
+    Ok(OpusStream {
+        head,
+        packets: OpusPackets::Matroska { reader: Box::new(reader), track_id },
+        end_granule,
+    })
+}
+
+// Samples per channel at 48 kHz in an Opus packet, read from its TOC byte (RFC 6716 3.1)
+fn opus_packet_frames(packet: &[u8]) -> u64 {
+    let Some(&toc) = packet.first() else {
+        return 0;
+    };
+    let config = usize::from(toc >> 3);
+    let frame_len = match config {
+        0..=11 => [480, 960, 1920, 2880][config % 4],
+        12..=15 => [480, 960][config % 2],
+        _ => [120, 240, 480, 960][config % 4],
+    };
+    let frame_count = match toc & 3 {
+        0 => 1,
+        1 | 2 => 2,
+        _ => packet.get(1).map_or(0, |count| u64::from(count & 0x3f)),
+    };
+    frame_len * frame_count
+}
+
+// Opus from an Ogg or WebM/Matroska file, read and decoded as playback reaches it. The length
+// comes from the container, so opening one to ask its duration doesn't read the whole file.
+struct OpusSource {
+    decoder: opus_decoder::OpusDecoder,
+    packets: OpusPackets,
+    channels: u16,
+    buffer: Vec<i16>,
+    filled: usize,
+    position: usize,
+    // Encoder delay still to drop, in interleaved samples
+    skip: usize,
+    // Interleaved samples left to play
+    remaining: usize,
+    total_frames: u64,
+}
+
+impl OpusSource {
+    fn open(file_path: &str) -> Result<Self, PlaybackError> {
+        use std::io::Read;
+
+        let mut magic = [0; 4];
+        File::open(file_path)
+            .and_then(|mut file| file.read_exact(&mut magic))
+            .map_err(|e| PlaybackError::File(format!("Failed to open file: {}", e)))?;
+        let stream = if &magic == b"OggS" {
+            ogg_opus_stream(file_path)
+        } else {
+            matroska_opus_stream(file_path)
+        };
+        stream.and_then(Self::new).map_err(PlaybackError::Decode)
+    }
+
+    fn new(stream: OpusStream) -> Result<Self, String> {
+        let head = &stream.head;
+        if head.len() < 19 || !head.starts_with(b"OpusHead") {
+            return Err("Invalid Opus header".to_string());
+        }
+        let channels = head[9];
+        let pre_skip = opus_pre_skip(head);
+        if head[18] != 0 || !(1..=2).contains(&channels) {
+            return Err("Only mono and stereo Opus is supported".to_string());
+        }
+        let decoder = opus_decoder::OpusDecoder::new(OPUS_SAMPLE_RATE, usize::from(channels))
+            .map_err(|e| format!("Failed to start Opus decoder: {}", e))?;
+
+        let total_frames = stream.end_granule.saturating_sub(pre_skip);
+        let channels_len = usize::from(channels);
+        Ok(Self {
+            decoder,
+            packets: stream.packets,
+            channels: u16::from(channels),
+            buffer: vec![0; OPUS_MAX_PACKET_FRAMES * channels_len],
+            filled: 0,
+            position: 0,
+            skip: pre_skip as usize * channels_len,
+            remaining: total_frames as usize * channels_len,
+            total_frames,
+        })
+    }
+}
+
+impl Iterator for OpusSource {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        loop {
+            if self.remaining == 0 {
+                return None;
+            }
+            if self.position < self.filled {
+                let sample = self.buffer[self.position];
+                self.position += 1;
+                if self.skip > 0 {
+                    self.skip -= 1;
+                    continue;
+                }
+                self.remaining -= 1;
+                return Some(sample);
+            }
+            let packet = self.packets.next_packet()?;
+            // A damaged packet is concealed instead of ending playback
+            let frames = match self.decoder.decode(&packet, &mut self.buffer, false) {
+                Ok(frames) => frames,
+                Err(_) => self.decoder.decode(&[], &mut self.buffer, false).unwrap_or(0),
+            };
+            self.filled = frames * usize::from(self.channels);
+            self.position = 0;
+        }
+    }
+}
+
+impl Source for OpusSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        OPUS_SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        Some(std::time::Duration::from_secs_f64(self.total_frames as f64 / f64::from(OPUS_SAMPLE_RATE)))
+    }
+}
+
+// The configured format for a device if it has one the device supports, keeping the default
+// config's channel count and sample format where possible
+fn requested_stream_config(device: &rodio::cpal::Device, device_name: &str) -> Option<rodio::cpal::SupportedStreamConfig> {
+    use rodio::cpal::traits::DeviceTrait;
+
+    let engine = audio_engine()?;
+    let sample_rate = {
+        let configs = engine.device_configs.lock().ok()?;
+        configs
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(device_name))
+            .map(|(_, config)| config.sample_rate)?
+    };
+    let default = device.default_output_config().ok()?;
+    if default.sample_rate().0 == sample_rate {
+        return Some(default);
+    }
+
+    let mut ranges: Vec<_> = device
+        .supported_output_configs()
+        .ok()?
+        .filter(|r| r.min_sample_rate().0 <= sample_rate && sample_rate <= r.max_sample_rate().0)
+        .collect();
+    ranges.sort_by_key(|r| (r.channels() != default.channels(), r.sample_format() != default.sample_format()));
+    let config = ranges.into_iter().next()?;
+    Some(config.with_sample_rate(rodio::cpal::SampleRate(sample_rate)))
+}
+
+// Channel count and sample rate an open output stream runs at
+type StreamFormat = (u16, u32);
+
+// An open stream plus its format, None when rodio picked the format itself
+type OpenedStream = (OutputStream, OutputStreamHandle, Option<StreamFormat>);
+
+fn open_with_config(device: &rodio::cpal::Device, config: rodio::cpal::SupportedStreamConfig) -> Result<OpenedStream, rodio::StreamError> {
+    let format = (config.channels(), config.sample_rate().0);
+    let (stream, handle) = OutputStream::try_from_device_config(device, config)?;
+    Ok((stream, handle, Some(format)))
+}
+
+// Open a device with its configured format, or its default if none is set or it's refused
+fn open_device_stream(device: &rodio::cpal::Device, device_name: &str) -> Result<OpenedStream, rodio::StreamError> {
+    use rodio::cpal::traits::DeviceTrait;
+
+    if let Some(config) = requested_stream_config(device, device_name) {
+        match open_with_config(device, config) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => eprintln!("[Playback] Configured format for '{}' failed ({}), using its default", device_name, e),
+        }
+    }
+    open_with_config(device, device.default_output_config()?)
+}
+
+// Open the system default output, or whichever device rodio falls back to if that fails
+fn open_default_stream() -> Result<OpenedStream, rodio::StreamError> {
+    use rodio::cpal::traits::{DeviceTrait, HostTrait};
+
+    if let Some(device) = rodio::cpal::default_host().default_output_device() {
+        if let Ok(config) = device.default_output_config() {
+            if let Ok(stream) = open_with_config(&device, config) {
+                return Ok(stream);
+            }
+        }
+    }
+    OutputStream::try_default().map(|(stream, handle)| (stream, handle, None))
+}
+
+// Open an output stream on the named device, falling back to the default if it isn't found.
+// Also reports the format of whichever device was actually opened, for match_stream_format.
+fn open_output_stream_with_format(device_name: Option<&str>) -> Result<OpenedStream, PlaybackError> {
+    if let Some(name) = device_name {
+        if let Some(device) = find_device_by_name(name) {
+            return match open_device_stream(&device, name) {
+                Ok(stream) => Ok(stream),
+                Err(e) if is_device_busy(&e) => open_default_for_busy(name),
+                Err(e) => Err(PlaybackError::Device(format!("Failed to open device: {}", e))),
+            };
+        }
+        note_device_resolution(name, default_output_device_name());
+    }
+    open_default_stream().map_err(|e| {
+        if is_device_busy(&e) {
+            PlaybackError::DeviceBusy(format!("Default output device is busy or unavailable: {}", e))
+        } else {
+            PlaybackError::Device(format!("Failed to open default device: {}", e))
+        }
+    })
+}
+
+// Whether a stream failed because the device is held exclusively by another app
+// (AUDCLNT_E_DEVICE_IN_USE) or has gone away, as opposed to a format/driver problem
+fn is_device_busy(error: &rodio::StreamError) -> bool {
+    use rodio::cpal::{BuildStreamError, DefaultStreamConfigError, SupportedStreamConfigsError};
+
+    match error {
+        rodio::StreamError::BuildStreamError(BuildStreamError::DeviceNotAvailable)
+        | rodio::StreamError::DefaultStreamConfigError(DefaultStreamConfigError::DeviceNotAvailable)
+        | rodio::StreamError::SupportedStreamConfigsError(SupportedStreamConfigsError::DeviceNotAvailable)
+        | rodio::StreamError::NoDevice => true,
+        other => {
+            let message = other.to_string().to_lowercase();
+            message.contains("0x8889000a") || message.contains("already in use")
+        }
+    }
+}
+
+// A named device is busy: tell the UI which one, then play on the shared-mode default instead
+fn open_default_for_busy(device_name: &str) -> Result<OpenedStream, PlaybackError> {
+    let fallback_is_same = default_output_device_name().is_some_and(|d| d.eq_ignore_ascii_case(device_name));
+    eprintln!(
+        "[Playback] Device '{}' is busy (exclusive mode?){}",
+        device_name,
+        if fallback_is_same { "" } else { ", falling back to the default output" }
+    );
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let _ = app_handle.emit_all("device-busy", device_name);
+    }
+
+    if fallback_is_same {
+        return Err(PlaybackError::DeviceBusy(format!("Device '{}' is busy or unavailable", device_name)));
+    }
+    open_default_stream().map_err(|e| {
+        PlaybackError::DeviceBusy(format!(
+            "Device '{}' is busy and the default output failed too: {}",
+            device_name, e
+        ))
+    })
+}
+
+// Convert a source to the stream's own rate and channel count up front instead of leaving it
+// to the backend, which some drivers get wrong (pitch/speed drift on 44.1k vs 48k)
+fn match_stream_format(
+    source: Box<dyn Source<Item = i16> + Send>,
+    format: Option<StreamFormat>,
+) -> Box<dyn Source<Item = i16> + Send> {
+    match format {
+        Some((channels, sample_rate)) if source.sample_rate() != sample_rate || source.channels() != channels => {
+            Box::new(rodio::source::UniformSourceIterator::<_, i16>::new(source, channels, sample_rate))
+        }
+        _ => source,
+    }
+}
+
+// Length of an audio file in seconds, counting samples when the decoder can't report it
+fn file_duration_secs(file_path: &str) -> Option<f64> {
+    // Counting samples is slow for long files, so cache by path and modification time
+    let modified = std::fs::metadata(file_path).and_then(|m| m.modified()).ok();
+    if let Ok(cache) = DURATION_CACHE.lock() {
+        if let Some((cached_modified, duration)) = cache.get(file_path) {
+            if *cached_modified == modified {
+                return Some(*duration);
+            }
+        }
+    }
+
+    let source = open_decoder(file_path).ok()?;
+    let duration = match source.total_duration() {
+        Some(duration) => duration.as_secs_f64(),
+        None => {
+            let samples_per_sec = source.sample_rate() as f64 * source.channels() as f64;
+            if samples_per_sec <= 0.0 {
+                return None;
+            }
+            source.count() as f64 / samples_per_sec
+        }
+    };
+
+    if let Ok(mut cache) = DURATION_CACHE.lock() {
+        cache.insert(file_path.to_string(), (modified, duration));
+    }
+    Some(duration)
+}
+
+// Trim window for playback. Frame offsets, when set, take precedence over seconds
+// because duration-based skipping rounds to the decoder's granularity
+#[derive(Debug, Clone, Copy, Default)]
+struct Trim {
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    start_frame: Option<u64>,
+    end_frame: Option<u64>,
+}
+
+impl Trim {
+    fn of(sound: &Sound) -> Self {
+        Self {
+            start_time: sound.start_time,
+            end_time: sound.end_time,
+            start_frame: sound.start_sample,
+            end_frame: sound.end_sample,
+        }
+    }
+
+    // Same window, but starting at the given point in seconds
+    fn starting_at(self, start: Option<f64>) -> Self {
+        match start {
+            Some(secs) => Self {
+                start_time: Some(secs),
+                start_frame: None,
+                ..self
+            },
+            None => self,
+        }
+    }
+
+    fn is_frame_accurate(&self) -> bool {
+        self.start_frame.is_some() || self.end_frame.is_some()
+    }
+}
+
+type LoopSource = Box<dyn Source<Item = i16> + Send>;
+
+// A trimmed region decoded in full, shared by every repeat
+#[derive(Clone)]
+struct DecodedRegion {
+    samples: Arc<[i16]>,
+    channels: u16,
+    sample_rate: u32,
+}
+
+#[derive(Clone)]
+enum LoopRegion {
+    Empty,
+    Pending,
+    Ready(DecodedRegion),
+    Failed,
+}
+
+// Live loop switch of a playing sound, plus what it needs to have its repeat region ready
+#[derive(Clone)]
+struct LoopControl {
+    enabled: Arc<AtomicBool>,
+    region: Arc<Mutex<LoopRegion>>,
+    file_path: String,
+    trim: Trim,
+}
+
+impl LoopControl {
+    fn new(file_path: &str, trim: Trim, enabled: bool) -> Self {
+        let control = Self {
+            enabled: Arc::new(AtomicBool::new(false)),
+            region: Arc::new(Mutex::new(LoopRegion::Empty)),
+            file_path: file_path.to_string(),
+            trim,
+        };
+        control.set(enabled);
+        control
+    }
+
+    // The region is queued for decoding before the flag flips, so the audio thread never
+    // sees looping on without a region on its way
+    fn set(&self, enabled: bool) {
+        if enabled {
+            self.prepare();
+        }
+        self.enabled.store(enabled, Ordering::SeqCst);
+    }
+
+    // Decode the repeat region on a background thread unless it's ready or already underway
+    fn prepare(&self) {
+        match self.region.lock() {
+            Ok(mut region) if matches!(*region, LoopRegion::Empty) => *region = LoopRegion::Pending,
+            _ => return,
+        }
+        let control = self.clone();
+        std::thread::spawn(move || {
+            let decoded = open_decoder(&control.file_path).ok().map(|source| {
+                let source = trim_source(source, control.trim);
+                let (channels, sample_rate) = (source.channels(), source.sample_rate());
+                DecodedRegion { samples: source.collect(), channels, sample_rate }
+            });
+            if let Ok(mut region) = control.region.lock() {
+                *region = decoded.map_or(LoopRegion::Failed, LoopRegion::Ready);
+            }
+        });
+    }
+}
+
+// One pass over a decoded region
+struct RegionPass {
+    region: DecodedRegion,
+    position: usize,
+}
+
+impl Iterator for RegionPass {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        let sample = *self.region.samples.get(self.position)?;
+        self.position += 1;
+        Some(sample)
+    }
+}
+
+impl Source for RegionPass {
+    fn current_frame_len(&self) -> Option<usize> {
+        Some(self.region.samples.len() - self.position)
+    }
+
+    fn channels(&self) -> u16 {
+        self.region.channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.region.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        None
+    }
+}
+
+// Plays the current pass, then repeats the trimmed region while the loop is on, so looping
+// can be switched on or off mid-playback. The region is decoded off the audio thread; if a
+// repeat comes due before it's ready, short stretches of silence bridge the gap.
+struct LiveLoop {
+    current: LoopSource,
+    control: LoopControl,
+    silence: usize,
+}
+
+impl Iterator for LiveLoop {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        if let Some(sample) = self.current.next() {
+            return Some(sample);
+        }
+        if self.silence > 0 {
+            self.silence -= 1;
+            return Some(0);
+        }
+        if !self.control.enabled.load(Ordering::Relaxed) {
+            return None;
+        }
+        // Never block the audio thread on the decoder storing its result
+        let region = match self.control.region.try_lock() {
+            Ok(region) => region.clone(),
+            Err(_) => LoopRegion::Pending,
+        };
+        match region {
+            LoopRegion::Ready(region) => {
+                self.current = Box::new(RegionPass { region, position: 0 });
+                self.current.next()
+            }
+            LoopRegion::Pending => {
+                // 10ms of whole frames, then check again
+                let channels = self.current.channels().max(1) as usize;
+                self.silence = channels * (self.current.sample_rate() as usize / 100).max(1) - 1;
+                Some(0)
+            }
+            LoopRegion::Empty | LoopRegion::Failed => None,
+        }
+    }
+}
+
+impl Source for LiveLoop {
+    fn current_frame_len(&self) -> Option<usize> {
+        if self.silence > 0 {
+            return Some(self.silence);
+        }
+        self.current.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.current.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.current.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        None
+    }
+}
+
+// Frame-accurate trim: skips and takes exact interleaved sample counts
+struct SampleTrim<S> {
+    inner: S,
+    skip: usize,
+    remaining: Option<usize>,
+}
+
+impl<S> Iterator for SampleTrim<S>
+where
+    S: Source,
+    S::Item: rodio::Sample,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<S::Item> {
+        while self.skip > 0 {
+            self.skip -= 1;
+            self.inner.next()?;
+        }
+        match self.remaining.as_mut() {
+            Some(0) => None,
+            Some(remaining) => {
+                *remaining -= 1;
+                self.inner.next()
+            }
+            None => self.inner.next(),
+        }
+    }
+}
+
+impl<S> Source for SampleTrim<S>
+where
+    S: Source,
+    S::Item: rodio::Sample,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        let inner = self.inner.current_frame_len().map(|len| len.saturating_sub(self.skip));
+        match (inner, self.remaining) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        None
+    }
+}
+
+// Averages each frame's channels into one, so nothing panned hard to one side is lost
+struct MonoDownmix<S> {
+    inner: S,
+}
+
+impl<S> Iterator for MonoDownmix<S>
+where
+    S: Source<Item = i16>,
+{
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        let channels = self.inner.channels().max(1);
+        let mut sum = self.inner.next()? as i32;
+        for _ in 1..channels {
+            sum += self.inner.next().unwrap_or(0) as i32;
+        }
+        Some((sum / channels as i32) as i16)
+    }
+}
+
+impl<S> Source for MonoDownmix<S>
+where
+    S: Source<Item = i16>,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        let channels = self.inner.channels().max(1) as usize;
+        self.inner.current_frame_len().map(|len| len / channels)
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        self.inner.total_duration()
+    }
+}
+
+// Apply a trim window to a decoded source
+fn trim_source<S>(source: S, trim: Trim) -> Box<dyn Source<Item = S::Item> + Send>
+where
+    S: Source + Send + 'static,
+    S::Item: rodio::Sample + Send,
+{
+    if trim.is_frame_accurate() {
+        // Seconds on either side are converted with the file's own sample rate
+        let rate = source.sample_rate() as f64;
+        let channels = source.channels().max(1) as u64;
+        let start_frame = trim
+            .start_frame
+            .unwrap_or_else(|| (trim.start_time.unwrap_or(0.0) * rate).round() as u64);
+        let end_frame = trim
+            .end_frame
+            .or_else(|| trim.end_time.map(|secs| (secs * rate).round() as u64));
+        return Box::new(SampleTrim {
+            inner: source,
+            skip: (start_frame * channels) as usize,
+            remaining: end_frame.map(|end| (end.saturating_sub(start_frame) * channels) as usize),
+        });
+    }
+
+    let start_secs = trim.start_time.unwrap_or(0.0);
+    let skipped: Box<dyn Source<Item = S::Item> + Send> = if start_secs > 0.0 {
+        Box::new(source.skip_duration(std::time::Duration::from_secs_f64(start_secs)))
+    } else {
+        Box::new(source)
+    };
+
+    match trim.end_time {
+        Some(end_secs) => {
+            let duration = (end_secs - start_secs).max(0.0);
+            Box::new(skipped.take_duration(std::time::Duration::from_secs_f64(duration)))
+        }
+        None => skipped,
+    }
+}
+
+// Players. Each runs on a pool worker until its sink drains or a stop_all ends its generation.
+impl AudioEngine {
+    #[allow(clippy::too_many_arguments)]
+    fn play_on_device(
+        self: &Arc<Self>,
+        generation: PlayGeneration,
+        sound_id: &str,
+        file_path: &str,
+        device_name: Option<&str>,
+        volume: f32,
+        trim: Trim,
+        loop_mode: bool,
+        playback_speed: f32,
+        random_offset: Option<f64>,
+        start_at: Option<std::time::Instant>,
+        delay: std::time::Duration,
+        downmix_mono: bool,
+    ) -> Result<(), PlaybackError> {
+        let source = open_decoder(file_path)?;
+
+        // Try to use specific device, fall back to default
+        let (_stream, stream_handle, format) = open_output_stream_with_format(device_name)?;
+
+        let sink = Arc::new(Sink::try_new(&stream_handle)
+            .map_err(|e| PlaybackError::Device(format!("Failed to create sink: {}", e)))?);
+        // Stopped while the file and device were opening
+        if self.play_stopped(sound_id, generation) {
+            return Ok(());
+        }
+        let playback = self.track(sound_id, device_name, volume, &sink);
+        let prebuffer_ms = self.prebuffer_ms.load(Ordering::SeqCst);
+        if start_at.is_some() || prebuffer_ms > 0 {
+            sink.pause();
+        }
+
+        // Apply trim settings and optional looping. The loop flag can be flipped while playing.
+        let speed = playback_speed.clamp(0.25, 2.0);
+        let looping = LoopControl::new(file_path, trim, loop_mode);
+        playback.set_loop_control(&looping);
+
+        // First pass starts at the random offset (if any), repeats cover the full region
+        let source = LiveLoop {
+            current: trim_source(source, trim.starting_at(random_offset)),
+            control: looping,
+            silence: 0,
+        }.speed(speed);
+        let source: Box<dyn Source<Item = i16> + Send> = if downmix_mono && source.channels() > 1 {
+            Box::new(MonoDownmix { inner: source })
+        } else {
+            Box::new(source)
+        };
+        // Echo layers wait out their delay as leading silence instead of asleep in a worker
+        let source: Box<dyn Source<Item = i16> + Send> = if delay.is_zero() {
+            source
+        } else {
+            Box::new(source.delay(delay))
+        };
+
+        sink.append(match_stream_format(source, format));
+
+        // Hold until the shared start time so every device begins together, and at least
+        // until the prebuffer has had time to fill
+        let start_at = match prebuffer_ms {
+            0 => start_at,
+            ms => {
+                let filled = std::time::Instant::now() + std::time::Duration::from_millis(ms);
+                Some(start_at.map_or(filled, |at| at.max(filled)))
+            }
+        };
+        if let Some(start_at) = start_at {
+            let wait = start_at.saturating_duration_since(std::time::Instant::now());
+            if !wait.is_zero() {
+                std::thread::sleep(wait);
+            }
+            sink.play();
+        }
+
+        // Poll for stop signal instead of blocking until end
+        while !sink.empty() {
+            if self.play_stopped(sound_id, generation) {
+                sink.stop();
+                return Ok(());
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+
+        Ok(())
+    }
+
+    // Play bass-boosted version using low-pass filter
+    #[allow(clippy::too_many_arguments)]
+    fn play_bass_boost(
+        self: &Arc<Self>,
+        generation: PlayGeneration,
+        sound_id: &str,
+        file_path: &str,
+        device_name: Option<&str>,
+        volume: f32,
+        trim: Trim,
+        playback_speed: f32,
+    ) -> Result<(), PlaybackError> {
+        let source = open_decoder(file_path)?
+            .convert_samples::<f32>(); // Convert to f32 for low_pass filter
+
+        let (_stream, stream_handle, format) = open_output_stream_with_format(device_name)?;
+
+        let sink = Arc::new(Sink::try_new(&stream_handle)
+            .map_err(|e| PlaybackError::Device(format!("Failed to create sink: {}", e)))?);
+        if self.play_stopped(sound_id, generation) {
+            return Ok(());
+        }
+        let _playback = self.track(sound_id, device_name, volume, &sink);
+
+        let speed = playback_speed.clamp(0.25, 2.0);
+
+        // Apply low-pass filter at 150Hz to isolate bass frequencies, then apply speed
+        let bass = trim_source(source, trim).low_pass(150).speed(speed).convert_samples::<i16>();
+        sink.append(match_stream_format(Box::new(bass), format));
+
+        // Poll for stop signal
+        while !sink.empty() {
+            if self.play_stopped(sound_id, generation) {
+                sink.stop();
+                return Ok(());
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+
+        Ok(())
+    }
+
+    // Play extreme bass version - heavily distorted bass effect
+    // Stacked low-pass filters + high amplification, replaces original audio
+    #[allow(clippy::too_many_arguments)]
+    fn play_fake_bass_boost(
+        self: &Arc<Self>,
+        generation: PlayGeneration,
+        sound_id: &str,
+        file_path: &str,
+        device_name: Option<&str>,
+        volume: f32,
+        trim: Trim,
+        playback_speed: f32,
+    ) -> Result<(), PlaybackError> {
+        let source = open_decoder(file_path)?
+            .convert_samples::<f32>();
+
+        let (_stream, stream_handle, format) = open_output_stream_with_format(device_name)?;
+
+        let sink = Arc::new(Sink::try_new(&stream_handle)
+            .map_err(|e| PlaybackError::Device(format!("Failed to create sink: {}", e)))?);
+        if self.play_stopped(sound_id, generation) {
+            return Ok(());
+        }
+        let _playback = self.track(sound_id, device_name, volume, &sink);
+
+        // Slow it down slightly for that deep fried effect
+        let speed = (playback_speed * 0.85).clamp(0.2, 2.0);
+
+        // MAXIMUM BASS: Stack multiple low-pass filters + extreme amplification
+        // This creates the classic "earrape" distorted bass meme sound
+        // Chain: low_pass(600) -> amplify 4x -> low_pass(300) -> amplify 4x
+        // Double filtering + double amplification = pure distorted bass destruction
+        let bass = trim_source(source, trim)
+            .low_pass(600)      // First pass: capture bass + low-mids
+            .amplify(4.0)       // Boost hard
+            .low_pass(300)      // Second pass: isolate the BASS
+            .amplify(4.0);      // BOOST HARDER (total 16x amplification)
+        sink.append(match_stream_format(Box::new(bass.speed(speed).convert_samples::<i16>()), format));
+
+        // Poll for stop signal
+        while !sink.empty() {
+            if self.play_stopped(sound_id, generation) {
+                sink.stop();
+                return Ok(());
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+
+        Ok(())
+    }
+
+    // Version with optional fade-in for crossfade support in queue mode
+    #[allow(clippy::too_many_arguments)]
+    fn play_on_device_with_fade(
+        self: &Arc<Self>,
+        generation: PlayGeneration,
+        sound_id: &str,
+        file_path: &str,
+        device_name: Option<&str>,
+        volume: f32,
+        trim: Trim,
+        fade_duration: Option<std::time::Duration>,
+        playback_speed: f32,
+    ) -> Result<(), PlaybackError> {
+        let source = open_decoder(file_path)?;
+
+        let (_stream, stream_handle, format) = open_output_stream_with_format(device_name)?;
+
+        let sink = Arc::new(Sink::try_new(&stream_handle)
+            .map_err(|e| PlaybackError::Device(format!("Failed to create sink: {}", e)))?);
+        if self.play_stopped(sound_id, generation) {
+            return Ok(());
+        }
+        let _playback = self.track(sound_id, device_name, volume, &sink);
+
+        let speed = playback_speed.clamp(0.25, 2.0);
+
+        // Apply fade_in and speed if needed
+        let with_speed = trim_source(source, trim).speed(speed);
+        let source: Box<dyn Source<Item = i16> + Send> = if let Some(fade) = fade_duration {
+            Box::new(with_speed.fade_in(fade))
+        } else {
+            Box::new(with_speed)
+        };
+        sink.append(match_stream_format(source, format));
+
+        // Poll for stop signal
+        while !sink.empty() {
+            if self.play_stopped(sound_id, generation) {
+                sink.stop();
+                return Ok(());
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+
+        Ok(())
+    }
+}
+
+// Final gain a sound plays at. Every playback path and get_effective_volume use this,
+// so what the UI displays always matches what plays
+fn effective_volume(state: &AudioState, sound: &Sound) -> f32 {
+    state.master_volume * sound.volume
+}
+
+fn gain_to_db(gain: f32) -> Option<f32> {
+    if gain > 0.0 {
+        Some(20.0 * gain.log10())
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct EffectiveVolume {
+    primary: f32,
+    #[serde(rename = "primaryDb")]
+    primary_db: Option<f32>,
+    monitor: Option<f32>,
+    #[serde(rename = "monitorDb")]
+    monitor_db: Option<f32>,
+}
+
+#[tauri::command]
+fn get_effective_volume(sound_id: String, state: State<AppState>, engine: State<Engine>) -> Result<EffectiveVolume, String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+
+    let gain = effective_volume(&audio_state, sound);
+    let targets = output_targets(&audio_state);
+    let plays_on_monitor = audio_state
+        .monitor_device
+        .as_ref()
+        .map(|monitor| targets.contains(&Some(monitor.clone())))
+        .unwrap_or(false);
+    let plays_on_primary = !audio_state.monitor_only || !plays_on_monitor;
+
+    // Device trims are applied per output on top of the sound's gain
+    let primary = if plays_on_primary {
+        gain * engine.device_gain(active_primary_device(&audio_state).as_deref())
+    } else {
+        0.0
+    };
+    let monitor = audio_state
+        .monitor_device
+        .as_deref()
+        .filter(|_| plays_on_monitor)
+        .map(|monitor| gain * engine.device_gain(Some(monitor)));
+    Ok(EffectiveVolume {
+        primary,
+        primary_db: gain_to_db(primary),
+        monitor,
+        monitor_db: monitor.and_then(gain_to_db),
+    })
+}
+
+// Everything needed to play a sound, captured while the state lock is held
+#[derive(Debug, Clone)]
+struct PlaybackPlan {
+    sound_id: String,
+    file_path: String,
+    volume: f32,
+    trim: Trim,
+    loop_mode: bool,
+    playback_speed: f32,
+    echo_delay: f32,
+    echo_volume: f32,
+    reverb_decay: f32,
+    bass_boost: f32,
+    fake_bass_boost: f32,
+    random_start: bool,
+    random_offset: Option<f64>,
+    // Device (the monitor) that gets a mono downmix instead of the file's channels
+    mono_device: Option<String>,
+}
+
+impl PlaybackPlan {
+    fn new(sound: &Sound, volume: f32) -> Self {
+        Self {
+            sound_id: sound.id.clone(),
+            file_path: sound.file_path.clone(),
+            volume,
+            trim: Trim::of(sound),
+            loop_mode: sound.loop_mode,
+            playback_speed: sound.playback_speed,
+            echo_delay: sound.echo_delay,
+            echo_volume: sound.echo_volume,
+            reverb_decay: sound.reverb_decay,
+            bass_boost: sound.bass_boost,
+            fake_bass_boost: sound.fake_bass_boost,
+            random_start: sound.random_start,
+            random_offset: None,
+            mono_device: None,
+        }
+    }
+
+    // Pick this trigger's random start within the trimmed region. Done once per
+    // trigger (outside the state lock) so every output device starts at the same spot.
+    fn pick_random_offset(&mut self) {
+        // Leave at least this much audio after a random start
+        const MIN_TAIL_SECS: f64 = 1.0;
+
+        if !self.random_start {
+            return;
+        }
+        let region_start = self.trim.start_time.unwrap_or(0.0);
+        let region_end = match self.trim.end_time.or_else(|| file_duration_secs(&self.file_path)) {
+            Some(end) => end,
+            None => return,
+        };
+        let latest_start = region_end - MIN_TAIL_SECS.min((region_end - region_start) / 2.0);
+        if latest_start > region_start {
+            self.random_offset = Some(rand::thread_rng().gen_range(region_start..latest_start));
+        }
+    }
+
+    // Trim for one-shot layers (echo, bass), which start at the random offset if any
+    fn one_shot_trim(&self) -> Trim {
+        self.trim.starting_at(self.random_offset)
+    }
+}
+
+// Output devices a sound plays on: the primary (None = system default) followed by
+// the monitor and any extra devices, skipping names that are already targeted
+fn output_targets(state: &AudioState) -> Vec<Option<String>> {
+    // Monitor-only mode keeps sounds off the primary/virtual-cable output entirely
+    if state.monitor_only {
+        if let Some(ref monitor) = state.monitor_device {
+            return vec![Some(monitor.clone())];
+        }
+    }
+
+    let mut targets = vec![active_primary_device(state)];
+    for name in state.monitor_device.iter().chain(state.extra_devices.iter()) {
+        let already_targeted = targets
+            .iter()
+            .flatten()
+            .any(|t| t.eq_ignore_ascii_case(name));
+        if !already_targeted {
+            targets.push(Some(name.clone()));
+        }
+    }
+
+    // The system default output as well, unless it's already one of the targets
+    if state.also_play_default && !targets.contains(&None) {
+        let default_targeted = default_output_device_name().is_some_and(|default| {
+            targets.iter().flatten().any(|t| t.eq_ignore_ascii_case(&default))
+        });
+        if !default_targeted {
+            targets.push(None);
+        }
+    }
+    targets
+}
+
+fn default_output_device_name() -> Option<String> {
+    use rodio::cpal::traits::{HostTrait, DeviceTrait};
+
+    rodio::cpal::default_host()
+        .default_output_device()
+        .and_then(|d| d.name().ok())
+}
+
+impl AudioEngine {
+    // Queue the players for a sound on every target device
+    fn queue_playback(self: &Arc<Self>, plan: &PlaybackPlan, targets: &[Option<String>]) {
+        if !self.queue_playback_on(&PLAYBACK_POOL, plan, targets) {
+            report_playback_error(&plan.sound_id, pool_full_error());
+        }
+    }
+
+    // Returns false when some of the players had to wait for a free worker
+    fn queue_playback_on(self: &Arc<Self>, pool: &'static PlaybackPool, plan: &PlaybackPlan, targets: &[Option<String>]) -> bool {
+        let generation = self.play_generation(&plan.sound_id);
+
+        // Each device opens its stream on its own worker, which can leave outputs tens of ms
+        // apart (heard as an echo on the monitor). With a pre-roll they all wait, paused,
+        // for one shared start time instead.
+        let sync_ms = self.output_sync_ms.load(Ordering::SeqCst);
+        let start_at = (sync_ms > 0 && targets.len() > 1)
+            .then(|| std::time::Instant::now() + std::time::Duration::from_millis(sync_ms));
+        // Whether every job got a worker straight away
+        let mut started = true;
+        for device in targets {
+            if self.hold_for_missing_primary(plan, device.as_deref()) {
+                continue;
+            }
+
+            // If extreme bass is enabled, play that INSTEAD of everything else (exclusive mode)
+            if plan.fake_bass_boost > 0.0 {
+                let p = plan.clone();
+                let engine = self.clone();
+                let device = device.clone();
+                started &= pool.execute(Box::new(move || {
+                    std::thread::sleep(std::time::Duration::from_millis(10));
+                    if let Err(e) = engine.play_fake_bass_boost(generation, &p.sound_id, &p.file_path, device.as_deref(), p.volume * p.fake_bass_boost, p.one_shot_trim(), p.playback_speed) {
+                        report_playback_error(&p.sound_id, e);
+                    }
+                }));
+                continue;
+            }
+
+            let downmix_mono = plan.mono_device.is_some() && plan.mono_device == *device;
+
+            // Play normal sound
+            let p = plan.clone();
+            let engine = self.clone();
+            let device_main = device.clone();
+            started &= pool.execute(Box::new(move || {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                if let Err(e) = engine.play_on_device(generation, &p.sound_id, &p.file_path, device_main.as_deref(), p.volume, p.trim, p.loop_mode, p.playback_speed, p.random_offset, start_at, std::time::Duration::ZERO, downmix_mono) {
+                    report_playback_error(&p.sound_id, e);
+                }
+            }));
+
+            // Play echo/reverb (delayed playback at lower volume)
+            // If reverb_decay > 0, create multiple echoes with decaying volume
+            if plan.echo_delay > 0.0 && plan.echo_volume > 0.0 {
+                let num_echoes = if plan.reverb_decay > 0.0 { 5 } else { 1 };
+                for i in 0..num_echoes {
+                    let echo_num = i + 1;
+                    let delay = plan.echo_delay * echo_num as f32;
+                    let decay_factor = if plan.reverb_decay > 0.0 {
+                        plan.reverb_decay.powf(echo_num as f32)
+                    } else {
+                        1.0
+                    };
+                    let echo_vol = plan.volume * plan.echo_volume * decay_factor;
+
+                    // Stop spawning if volume becomes negligible
+                    if echo_vol < 0.01 {
+                        break;
+                    }
+
+                    let p = plan.clone();
+                    let engine = self.clone();
+                    let device_echo = device.clone();
+                    let delay = std::time::Duration::from_secs_f32(delay);
+                    started &= pool.execute(Box::new(move || {
+                        if let Err(e) = engine.play_on_device(generation, &p.sound_id, &p.file_path, device_echo.as_deref(), echo_vol, p.one_shot_trim(), false, p.playback_speed, None, None, delay, downmix_mono) {
+                            report_playback_error(&p.sound_id, e);
+                        }
+                    }));
+                }
+            }
+
+            // Play bass boost (low-pass filtered extra bass layer)
+            if plan.bass_boost > 0.0 {
+                let p = plan.clone();
+                let engine = self.clone();
+                let device_bass = device.clone();
+                started &= pool.execute(Box::new(move || {
+                    if let Err(e) = engine.play_bass_boost(generation, &p.sound_id, &p.file_path, device_bass.as_deref(), p.volume * p.bass_boost, p.one_shot_trim(), p.playback_speed) {
+                        report_playback_error(&p.sound_id, e);
+                    }
+                }));
+            }
+        }
+
+        started
+    }
+}
+
+// Side effects of a pad being triggered: stats, notification, webhook and overlay
+fn sound_started(state: &State<AppState>, sound: &Sound) {
+    record_play(state, &sound.id);
+    notify_sound_played(sound);
+    fire_play_webhook(sound);
+    push_overlay_update(OverlayUpdate::Playing(NowPlayingOverlay {
+        sound_id: sound.id.clone(),
+        name: sound.name.clone(),
+        color: sound.color.clone(),
+    }));
+}
+
+// Payload of the now-playing-overlay event, for on-stream indicators
+#[derive(Debug, Clone, Serialize)]
+struct NowPlayingOverlay {
+    #[serde(rename = "soundId")]
+    sound_id: String,
+    name: String,
+    color: Option<String>,
+}
+
+enum OverlayUpdate {
+    Playing(NowPlayingOverlay),
+    // Nothing is playing any more
+    Cleared,
+}
+
+// Overlay events go out at most every 100ms. Updates in between replace each other and
+// the latest is sent when the interval is up, so the overlay always ends on the right state.
+const OVERLAY_MIN_INTERVAL_MS: u64 = 100;
+
+#[derive(Default)]
+struct OverlayThrottle {
+    last_emit: Option<std::time::Instant>,
+    pending: Option<OverlayUpdate>,
+    flush_scheduled: bool,
+}
+
+lazy_static::lazy_static! {
+    static ref OVERLAY_THROTTLE: Mutex<OverlayThrottle> = Mutex::new(OverlayThrottle::default());
+}
+
+fn push_overlay_update(update: OverlayUpdate) {
+    let Ok(mut overlay) = OVERLAY_THROTTLE.lock() else {
+        return;
+    };
+    overlay.pending = Some(update);
+    if overlay.flush_scheduled {
+        return;
+    }
+
+    let interval = std::time::Duration::from_millis(OVERLAY_MIN_INTERVAL_MS);
+    let wait = overlay
+        .last_emit
+        .map(|last| interval.saturating_sub(last.elapsed()))
+        .unwrap_or_default();
+    if wait.is_zero() {
+        flush_overlay(&mut overlay);
+        return;
+    }
+
+    overlay.flush_scheduled = true;
+    std::thread::spawn(move || {
+        std::thread::sleep(wait);
+        if let Ok(mut overlay) = OVERLAY_THROTTLE.lock() {
+            overlay.flush_scheduled = false;
+            flush_overlay(&mut overlay);
+        }
+    });
+}
+
+fn flush_overlay(overlay: &mut OverlayThrottle) {
+    let Some(update) = overlay.pending.take() else {
+        return;
+    };
+    overlay.last_emit = Some(std::time::Instant::now());
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let _ = match update {
+            OverlayUpdate::Playing(now_playing) => app_handle.emit_all("now-playing-overlay", now_playing),
+            OverlayUpdate::Cleared => app_handle.emit_all("now-playing-overlay-cleared", ()),
+        };
+    }
+}
+
+fn record_play(state: &State<AppState>, sound_id: &str) {
+    if let Ok(mut audio_state) = state.lock() {
+        audio_state.stats.record_play(sound_id);
+        save_stats(&audio_state.stats);
+    }
+}
+
+// Desktop notification for sounds with notify_on_play. A sound notifies at most every
+// 5 seconds and all sounds together at most once a second, so loops and spam don't flood.
+fn notify_sound_played(sound: &Sound) {
+    if !sound.notify_on_play {
+        return;
+    }
+    let Some(app_handle) = APP_HANDLE.get() else {
+        return;
+    };
+
+    let now = std::time::Instant::now();
+    {
+        let (Ok(mut per_sound), Ok(mut any)) = (LAST_NOTIFICATIONS.lock(), LAST_ANY_NOTIFICATION.lock()) else {
+            return;
+        };
+        if any.is_some_and(|last| now.duration_since(last).as_millis() < 1000) {
+            return;
+        }
+        if per_sound
+            .get(&sound.id)
+            .is_some_and(|last| now.duration_since(*last).as_secs() < 5)
+        {
+            return;
+        }
+        per_sound.insert(sound.id.clone(), now);
+        *any = Some(now);
+    }
+
+    let identifier = app_handle.config().tauri.bundle.identifier.clone();
+    let _ = tauri::api::notification::Notification::new(identifier)
+        .title("MotoBoard")
+        .body(format!("Played {}", sound.name))
+        .show();
+}
+
+// POST the sound's webhook on the async runtime so a slow or dead endpoint never holds up
+// playback. Failures only land in the last-error slot.
+fn fire_play_webhook(sound: &Sound) {
+    use tauri::api::http::{Body, ClientBuilder, HttpRequestBuilder};
+
+    let Some(url) = sound.on_play_webhook.clone() else {
+        return;
+    };
+    let sound_id = sound.id.clone();
+    let payload = serde_json::json!({
+        "event": "play",
+        "soundId": sound.id,
+        "name": sound.name,
+    });
+
+    tauri::async_runtime::spawn(async move {
+        let result = async {
+            let client = ClientBuilder::new()
+                .connect_timeout(std::time::Duration::from_secs(3))
+                .build()
+                .map_err(|e| e.to_string())?;
+            let request = HttpRequestBuilder::new("POST", &url)
+                .map_err(|e| e.to_string())?
+                .body(Body::Json(payload))
+                .timeout(std::time::Duration::from_secs(5));
+            let response = client.send(request).await.map_err(|e| e.to_string())?;
+            if !response.status().is_success() {
+                return Err(format!("HTTP {}", response.status()));
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Err(e) = result {
+            report_playback_error(&sound_id, PlaybackError::Webhook(format!("Webhook {} failed: {}", url, e)));
+        }
+    });
+}
+
+#[tauri::command]
+fn set_sound_color(sound_id: String, color: Option<String>, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get_mut(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+    sound.color = color.filter(|c| !c.trim().is_empty());
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_sound_trigger_on_release(sound_id: String, enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get_mut(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+    sound.trigger_on_release = enabled;
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+// Limit a sound's keybind to when a matching window is focused, empty clears it
+#[tauri::command]
+fn set_sound_window_filter(sound_id: String, filter: Option<String>, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get_mut(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+    sound.active_window_filter = filter.map(|f| f.trim().to_string()).filter(|f| !f.is_empty());
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+// Title and process of the focused window, for filling in a filter (None where unsupported)
+#[tauri::command]
+fn get_foreground_window() -> Option<ForegroundWindow> {
+    foreground_window()
+}
+
+// Set or clear the URL POSTed when a sound plays
+#[tauri::command]
+fn set_sound_webhook(sound_id: String, url: Option<String>, state: State<AppState>) -> Result<(), String> {
+    let url = url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty());
+    if let Some(ref url) = url {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err("Webhook URL must start with http:// or https://".to_string());
+        }
+    }
+
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get_mut(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+    sound.on_play_webhook = url;
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_sound_notify_on_play(sound_id: String, enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get_mut(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+    sound.notify_on_play = enabled;
+    save_sounds(&audio_state.sounds);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_play_stats(state: State<AppState>) -> Result<HashMap<String, SoundStats>, String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    Ok(audio_state.stats.sounds.clone())
+}
+
+// Reset session counts now, optionally wiping all-time counts as well
+#[tauri::command]
+fn reset_play_stats(all_time: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    if all_time {
+        audio_state.stats.sounds.clear();
+    }
+    audio_state.stats.reset_session();
+    save_stats(&audio_state.stats);
+    drop(audio_state);
+    emit_stats_reset();
+    Ok(())
+}
+
+#[tauri::command]
+fn set_stats_reset(period: StatsResetPeriod, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.stats_reset = period;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Pad grid mouse mapping: click -> play_sound, Shift+click -> play_sound_on_monitor_only,
+// right-click -> stop_sound
+#[tauri::command]
+fn play_sound(sound_id: String, state: State<AppState>, engine: State<Engine>) -> Result<(), String> {
+    start_sound(sound_id, None, false, &state, &engine)
+}
+
+// Audition a pad on the monitor device only, keeping it off the stream/voice output.
+// Previews don't count as plays.
+#[tauri::command]
+fn play_sound_on_monitor_only(sound_id: String, state: State<AppState>, engine: State<Engine>) -> Result<(), String> {
+    start_sound(sound_id, None, true, &state, &engine)
+}
+
+// Stop every playing instance of one pad, leaving other sounds alone
+#[tauri::command]
+fn stop_sound(sound_id: String, engine: State<Engine>) -> Result<(), String> {
+    engine.stop_sounds(&HashSet::from([sound_id]));
+    Ok(())
+}
+
+// Play once at a different sound volume (still scaled by master). The saved volume is untouched.
+#[tauri::command]
+fn play_sound_with_volume(
+    sound_id: String,
+    volume_override: f32,
+    state: State<AppState>,
+    engine: State<Engine>,
+) -> Result<(), String> {
+    start_sound(sound_id, Some(volume_override), false, &state, &engine)
+}
+
+fn start_sound(
+    sound_id: String,
+    volume_override: Option<f32>,
+    monitor_only: bool,
+    state: &State<AppState>,
+    engine: &State<Engine>,
+) -> Result<(), String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+
+    let mut sound = audio_state
+        .sounds
+        .get(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?
+        .clone();
+    if let Some(volume) = volume_override {
+        sound.volume = volume.clamp(0.0, 2.0); // Same range as update_sound_settings
+    }
+
+    if !PathBuf::from(&sound.file_path).exists() {
+        return Err("Sound file not found".to_string());
+    }
+
+    let mut plan = PlaybackPlan::new(&sound, effective_volume(&audio_state, &sound));
+    plan.mono_device = mono_monitor_device(&audio_state);
+    let targets = if monitor_only {
+        let monitor = audio_state
+            .monitor_device
+            .clone()
+            .ok_or_else(|| "No monitor device set".to_string())?;
+        vec![Some(monitor)]
+    } else {
+        output_targets(&audio_state)
+    };
+    let exclusive_members = sound
+        .exclusive_group
+        .as_deref()
+        .map(|group| exclusive_group_members(&audio_state.sounds, group));
+    let max_sounds = audio_state.max_concurrent_sounds;
+    let policy = audio_state.concurrency_policy;
+
+    // Drop the lock before spawning threads
+    drop(audio_state);
+
+    // Only one sound per exclusive group plays at a time
+    if let Some(ref members) = exclusive_members {
+        engine.stop_sounds(members);
+    }
+
+    if !engine.make_room_for_sound(&sound_id, max_sounds, policy) {
+        return Err("Maximum simultaneous sounds reached".to_string());
+    }
+
+    if !monitor_only {
+        sound_started(state, &sound);
+    }
+
+    plan.pick_random_offset();
+    engine.queue_playback(&plan, &targets);
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct TrimPreviewProgress {
+    #[serde(rename = "soundId")]
+    sound_id: String,
+    position: f64,
+    remaining: f64,
+    finished: bool,
+}
+
+// Play just a trim region, emitting playhead progress until it ends or is stopped
+fn play_trim_preview(
+    app_handle: &AppHandle,
+    sound_id: &str,
+    file_path: &str,
+    device_name: Option<&str>,
+    volume: f32,
+    start: f64,
+    end: f64,
+) -> Result<(), PlaybackError> {
+    let engine: State<Engine> = app_handle.state();
+    let generation = engine.generation();
+    let source = open_decoder(file_path)?;
+    let (_stream, stream_handle, format) = open_output_stream_with_format(device_name)?;
+    let sink = Arc::new(Sink::try_new(&stream_handle)
+        .map_err(|e| PlaybackError::Device(format!("Failed to create sink: {}", e)))?);
+    let _playback = engine.track(sound_id, device_name, volume, &sink);
+    let length = end - start;
+    let region = source
+        .skip_duration(std::time::Duration::from_secs_f64(start))
+        .take_duration(std::time::Duration::from_secs_f64(length));
+    sink.append(match_stream_format(Box::new(region), format));
+
+    let started = std::time::Instant::now();
+    while !sink.empty() {
+        if engine.stopped_since(generation) {
+            sink.stop();
+            break;
+        }
+        let elapsed = started.elapsed().as_secs_f64().min(length);
+        let _ = app_handle.emit_all("trim-preview-progress", TrimPreviewProgress {
+            sound_id: sound_id.to_string(),
+            position: start + elapsed,
+            remaining: length - elapsed,
+            finished: false,
+        });
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    let _ = app_handle.emit_all("trim-preview-progress", TrimPreviewProgress {
+        sound_id: sound_id.to_string(),
+        position: end,
+        remaining: 0.0,
+        finished: true,
+    });
+    Ok(())
+}
+
+// Audition a trim region on the monitor device without changing the stored sound
+#[tauri::command]
+fn preview_trim(
+    app_handle: AppHandle,
+    sound_id: String,
+    start: f64,
+    end: f64,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?
+        .clone();
+    let device = audio_state
+        .monitor_device
+        .clone()
+        .or_else(|| active_primary_device(&audio_state));
+    let volume = effective_volume(&audio_state, &sound);
+    drop(audio_state);
+
+    let duration = file_duration_secs(&sound.file_path)
+        .ok_or_else(|| "Could not determine sound duration".to_string())?;
+    let start = start.max(0.0);
+    let end = end.min(duration);
+    if start >= end {
+        return Err("Start time must be before end time".to_string());
+    }
+
+    let sound_id = sound.id.clone();
+    let started = PLAYBACK_POOL.execute(Box::new(move || {
+        if let Err(e) = play_trim_preview(&app_handle, &sound.id, &sound.file_path, device.as_deref(), volume, start, end) {
+            report_playback_error(&sound.id, e);
+        }
+    }));
+    if !started {
+        report_playback_error(&sound_id, pool_full_error());
+    }
+    Ok(())
+}
+
+// Check that a sound's file still decodes on this machine without playing it. Errors keep
+// their kind, so a missing file ("file") is distinguishable from one that won't decode ("decode").
+#[tauri::command]
+fn verify_sound(sound_id: String, state: State<AppState>) -> Result<(), PlaybackError> {
+    // Decoding this much is enough to hit codec and corruption problems near the start
+    const VERIFY_SECS: u64 = 10;
+
+    let file_path = {
+        let audio_state = state.lock().map_err(|e| PlaybackError::File(e.to_string()))?;
+        audio_state
+            .sounds
+            .get(&sound_id)
+            .map(|s| s.file_path.clone())
+            .ok_or_else(|| PlaybackError::File("Sound not found".to_string()))?
+    };
+    if !PathBuf::from(&file_path).exists() {
+        return Err(PlaybackError::File(format!("File not found: {}", file_path)));
+    }
+
+    let source = open_decoder(&file_path)?;
+    let limit = source.sample_rate() as u64 * source.channels() as u64 * VERIFY_SECS;
+    if source.take(limit as usize).count() == 0 {
+        return Err(PlaybackError::Decode("The file opened but no audio could be decoded".to_string()));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn get_sound_duration(sound_id: String, state: State<AppState>) -> Result<f64, String> {
+    let file_path = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state
+            .sounds
+            .get(&sound_id)
+            .map(|s| s.file_path.clone())
+            .ok_or_else(|| "Sound not found".to_string())?
+    };
+    file_duration_secs(&file_path).ok_or_else(|| "Could not determine sound duration".to_string())
+}
+
+// Mono samples (-1..1) for a time window of a sound's file, for zoomed-in waveform editing.
+// The window is decoded as a stream and reduced to at most max_samples points, each the
+// loudest sample of its stretch so short transients stay visible.
+#[tauri::command]
+fn get_pcm_region(
+    sound_id: String,
+    start: f64,
+    end: f64,
+    max_samples: usize,
+    state: State<AppState>,
+) -> Result<Vec<f32>, String> {
+    let file_path = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state
+            .sounds
+            .get(&sound_id)
+            .map(|s| s.file_path.clone())
+            .ok_or_else(|| "Sound not found".to_string())?
+    };
+
+    let start = start.max(0.0);
+    let end = match file_duration_secs(&file_path) {
+        Some(duration) => end.min(duration),
+        None => end,
+    };
+    if start >= end {
+        return Err(format!("Empty region: {:.3}s to {:.3}s", start, end));
+    }
+    if max_samples == 0 {
+        return Ok(Vec::new());
+    }
+
+    let source = open_decoder(&file_path).map_err(|e| e.to_string())?;
+    let channels = source.channels().max(1) as usize;
+    let rate = source.sample_rate() as f64;
+    let skip = (start * rate).round() as usize * channels;
+    let frames = (((end - start) * rate).round() as usize).max(1);
+    let per_point = frames.div_ceil(max_samples);
+
+    let mut points = Vec::with_capacity(frames.min(max_samples));
+    let mut samples = source.skip(skip).take(frames * channels);
+    let mut peak = 0.0f32;
+    let mut in_point = 0;
+    loop {
+        // Average the channels of one frame into a mono sample
+        let mut sum = 0.0f32;
+        let mut read = 0;
+        for sample in samples.by_ref().take(channels) {
+            sum += sample as f32 / i16::MAX as f32;
+            read += 1;
+        }
+        if read == 0 {
+            break;
+        }
+        let mono = sum / read as f32;
+        if mono.abs() > peak.abs() {
+            peak = mono;
+        }
+        in_point += 1;
+        if in_point == per_point {
+            points.push(peak.clamp(-1.0, 1.0));
+            peak = 0.0;
+            in_point = 0;
+        }
+    }
+    if in_point > 0 {
+        points.push(peak.clamp(-1.0, 1.0));
+    }
+    Ok(points)
+}
+
+// Bake a sound's trim, speed and volume into a new WAV, using the same trim and speed
+// stages as playback so the file matches what plays. Loops render a single pass.
+#[tauri::command]
+fn render_sound(sound_id: String, dest_path: String, state: State<AppState>) -> Result<(), String> {
+    let sound = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state
+            .sounds
+            .get(&sound_id)
+            .cloned()
+            .ok_or_else(|| "Sound not found".to_string())?
+    };
+
+    let source = open_decoder(&sound.file_path).map_err(|e| e.to_string())?;
+    let channels = source.channels();
+    let sample_rate = source.sample_rate();
+    let speed = sound.playback_speed.clamp(0.25, 2.0);
+
+    // Speed works by relabeling the sample rate, so convert back to the file's own rate
+    let processed = trim_source(source, Trim::of(&sound))
+        .speed(speed)
+        .amplify(sound.volume);
+    let samples: Vec<i16> = rodio::source::UniformSourceIterator::new(processed, channels, sample_rate).collect();
+    if samples.is_empty() {
+        return Err("Nothing to render, the trimmed region is empty".to_string());
+    }
+
+    write_wav(std::path::Path::new(&dest_path), channels, sample_rate, &samples)
+        .map_err(|e| format!("Failed to write WAV: {}", e))
+}
+
+// Join two sounds into one WAV, the tail of the first overlapping the head of the second by
+// crossfade_ms with equal-power fades. Trims apply; both are converted to a shared format.
+#[tauri::command]
+fn merge_sounds(
+    first_id: String,
+    second_id: String,
+    crossfade_ms: u32,
+    dest_path: String,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let (first, second) = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        let get = |id: &String| {
+            audio_state
+                .sounds
+                .get(id)
+                .cloned()
+                .ok_or_else(|| format!("Sound not found: {}", id))
+        };
+        (get(&first_id)?, get(&second_id)?)
+    };
+
+    let first_source = open_decoder(&first.file_path).map_err(|e| format!("{}: {}", first.name, e))?;
+    let second_source = open_decoder(&second.file_path).map_err(|e| format!("{}: {}", second.name, e))?;
+    let channels = first_source.channels().max(second_source.channels());
+    let sample_rate = first_source.sample_rate().max(second_source.sample_rate());
+
+    let decode = |source: AudioDecoder, sound: &Sound| {
+        let samples: Vec<i16> =
+            rodio::source::UniformSourceIterator::new(trim_source(source, Trim::of(sound)), channels, sample_rate)
+                .collect();
+        if samples.is_empty() {
+            return Err(format!("{}: nothing decoded from the trimmed region", sound.name));
+        }
+        Ok(samples)
+    };
+    let a = decode(first_source, &first)?;
+    let b = decode(second_source, &second)?;
+
+    let frames = |samples: &[i16]| samples.len() / channels as usize;
+    let overlap = (crossfade_ms as u64 * sample_rate as u64 / 1000) as usize;
+    if overlap > frames(&a) || overlap > frames(&b) {
+        return Err(format!("Crossfade of {}ms is longer than one of the clips", crossfade_ms));
+    }
+
+    let overlap_samples = overlap * channels as usize;
+    let head = a.len() - overlap_samples;
+    let mut samples = Vec::with_capacity(a.len() + b.len() - overlap_samples);
+    samples.extend_from_slice(&a[..head]);
+    for (i, (&out, &inc)) in a[head..].iter().zip(&b[..overlap_samples]).enumerate() {
+        // Equal power: cos/sin gains keep loudness steady through the overlap
+        let t = (i / channels as usize) as f32 / overlap.max(1) as f32;
+        let angle = t * std::f32::consts::FRAC_PI_2;
+        let mixed = out as f32 * angle.cos() + inc as f32 * angle.sin();
+        samples.push(mixed.clamp(i16::MIN as f32, i16::MAX as f32) as i16);
+    }
+    samples.extend_from_slice(&b[overlap_samples..]);
+
+    write_wav(std::path::Path::new(&dest_path), channels, sample_rate, &samples)
+        .map_err(|e| format!("Failed to write WAV: {}", e))
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SoundInfo {
+    #[serde(rename = "sampleRate")]
+    sample_rate: u32,
+    channels: u16,
+    duration: Option<f64>,
+    format: String,
+    #[serde(rename = "fileSize")]
+    file_size: u64,
+    // Average over the whole file, in kbps
+    bitrate: Option<u32>,
+}
+
+fn format_name(file_path: &str) -> String {
+    let extension = std::path::Path::new(file_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    match extension.as_str() {
+        "mp3" => "MP3".to_string(),
+        "wav" => "WAV".to_string(),
+        "ogg" => "Ogg Vorbis".to_string(),
+        "flac" => "FLAC".to_string(),
+        "webm" => "WebM".to_string(),
+        "mka" => "Matroska".to_string(),
+        "" => "Unknown".to_string(),
+        other => other.to_uppercase(),
+    }
+}
+
+#[tauri::command]
+fn get_sound_info(sound_id: String, state: State<AppState>) -> Result<SoundInfo, String> {
+    let file_path = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state
+            .sounds
+            .get(&sound_id)
+            .map(|s| s.file_path.clone())
+            .ok_or_else(|| "Sound not found".to_string())?
+    };
+
+    let metadata = std::fs::metadata(&file_path).map_err(|e| e.to_string())?;
+    let modified = metadata.modified().ok();
+    if let Ok(cache) = SOUND_INFO_CACHE.lock() {
+        if let Some((cached_modified, info)) = cache.get(&file_path) {
+            if *cached_modified == modified {
+                return Ok(info.clone());
+            }
+        }
+    }
+
+    let source = open_decoder(&file_path).map_err(|e| e.to_string())?;
+    let sample_rate = source.sample_rate();
+    let channels = source.channels();
+    drop(source);
+
+    let file_size = metadata.len();
+    let duration = file_duration_secs(&file_path);
+    let bitrate = duration
+        .filter(|d| *d > 0.0)
+        .map(|d| (file_size as f64 * 8.0 / d / 1000.0).round() as u32);
+
+    let info = SoundInfo {
+        sample_rate,
+        channels,
+        duration,
+        format: format_name(&file_path),
+        file_size,
+        bitrate,
+    };
+
+    if let Ok(mut cache) = SOUND_INFO_CACHE.lock() {
+        cache.insert(file_path, (modified, info.clone()));
+    }
+    Ok(info)
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct AutoTrimResult {
+    #[serde(rename = "startTime")]
+    start_time: Option<f64>,
+    #[serde(rename = "endTime")]
+    end_time: Option<f64>,
+    changed: bool,
+    message: Option<String>,
+}
+
+// Find the first and last points louder than the threshold, in seconds
+fn detect_audible_region(file_path: &str, threshold_db: f32) -> Result<Option<(f64, f64, f64)>, String> {
+    let source = open_decoder(file_path).map_err(|e| e.to_string())?;
+    let channels = source.channels().max(1) as usize;
+    let sample_rate = source.sample_rate().max(1) as f64;
+    let threshold = 10f32.powf(threshold_db / 20.0);
+
+    let mut first: Option<usize> = None;
+    let mut last: Option<usize> = None;
+    let mut total = 0usize;
+    for (index, sample) in source.convert_samples::<f32>().enumerate() {
+        if sample.abs() > threshold {
+            if first.is_none() {
+                first = Some(index);
+            }
+            last = Some(index);
+        }
+        total = index + 1;
+    }
+
+    let to_secs = |index: usize| (index / channels) as f64 / sample_rate;
+    Ok(match (first, last) {
+        (Some(first), Some(last)) => Some((to_secs(first), to_secs(last), to_secs(total))),
+        _ => None,
+    })
+}
+
+// Set start/end trim to skip leading and trailing silence
+#[tauri::command]
+fn auto_trim_silence(sound_id: String, threshold_db: f32, state: State<AppState>) -> Result<AutoTrimResult, String> {
+    // Padding kept around the detected audio so attacks and tails aren't clipped
+    const PADDING_SECS: f64 = 0.05;
+
+    let file_path = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state
+            .sounds
+            .get(&sound_id)
+            .map(|s| s.file_path.clone())
+            .ok_or_else(|| "Sound not found".to_string())?
+    };
+
+    // Decode without holding the lock, long files take a while
+    let region = detect_audible_region(&file_path, threshold_db.min(0.0))?;
+
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sound = audio_state
+        .sounds
+        .get_mut(&sound_id)
+        .ok_or_else(|| "Sound not found".to_string())?;
+
+    let (first, last, duration) = match region {
+        Some(region) => region,
+        None => {
+            return Ok(AutoTrimResult {
+                start_time: sound.start_time,
+                end_time: sound.end_time,
+                changed: false,
+                message: Some(format!("Whole clip is below {} dB, nothing trimmed", threshold_db)),
+            });
+        }
+    };
+
+    sound.start_time = Some((first - PADDING_SECS).max(0.0));
+    sound.end_time = Some((last + PADDING_SECS).min(duration));
+    let result = AutoTrimResult {
+        start_time: sound.start_time,
+        end_time: sound.end_time,
+        changed: true,
+        message: None,
+    };
+    save_sounds(&audio_state.sounds);
+    Ok(result)
+}
+
+// Switch looping on or off for a sound that is already playing, without restarting it.
+// Only the live playback changes; the saved loop setting is left alone.
+#[tauri::command]
+fn set_active_loop(sound_id: String, enabled: bool, engine: State<Engine>) -> Result<(), String> {
+    if engine.set_loop(&sound_id, enabled) {
+        Ok(())
     } else {
-        OutputStream::try_default()
-            .map_err(|e| format!("Failed to open default device: {}", e))?
+        Err("Sound is not playing".to_string())
+    }
+}
+
+#[tauri::command]
+fn stop_device(device_name: String, engine: State<Engine>) -> Result<usize, String> {
+    Ok(engine.stop_device(&device_name))
+}
+
+#[tauri::command]
+fn stop_all() -> Result<(), String> {
+    // Ends the current generation so every playing and pending sound stops, queue included
+    stop_all_playback(StopAllSource::Command);
+    Ok(())
+}
+
+// Last-resort stop: everything stop_all does, plus emptying the queue so nothing restarts
+#[tauri::command]
+fn force_stop_all() -> Result<(), String> {
+    stop_all_playback(StopAllSource::Command);
+    SOUND_QUEUE.lock().map_err(|e| e.to_string())?.clear();
+    Ok(())
+}
+
+#[tauri::command]
+fn get_stop_all_log() -> Result<Vec<StopAllLogEntry>, String> {
+    let log = STOP_ALL_LOG.lock().map_err(|e| e.to_string())?;
+    Ok(log.iter().cloned().collect())
+}
+
+#[tauri::command]
+fn add_to_queue(sound_id: String) -> Result<Vec<String>, String> {
+    let mut queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?;
+    queue.push(sound_id);
+    Ok(queue.clone())
+}
+
+#[tauri::command]
+fn remove_from_queue(index: usize) -> Result<Vec<String>, String> {
+    let mut queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?;
+    if index < queue.len() {
+        queue.remove(index);
+    }
+    Ok(queue.clone())
+}
+
+#[tauri::command]
+fn clear_queue() -> Result<(), String> {
+    let mut queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?;
+    queue.clear();
+    QUEUE_PLAYING.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_queue() -> Result<Vec<String>, String> {
+    let queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?;
+    Ok(queue.clone())
+}
+
+#[tauri::command]
+fn play_queue(state: State<AppState>, engine: State<Engine>) -> Result<(), String> {
+    // Don't start if already playing
+    if QUEUE_PLAYING.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    let queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?.clone();
+    if queue.is_empty() {
+        return Ok(());
+    }
+
+    // Get all sound data we need
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    let sounds_data: Vec<_> = queue.iter().filter_map(|id| {
+        audio_state.sounds.get(id).map(|s| {
+            (
+                s.id.clone(),
+                s.file_path.clone(),
+                effective_volume(&audio_state, s),
+                Trim::of(s),
+                s.playback_speed,
+            )
+        })
+    }).collect();
+    let primary_device = active_primary_device(&audio_state);
+    let monitor_device = audio_state.monitor_device.clone();
+    let crossfade_ms = audio_state.crossfade_duration;
+    drop(audio_state);
+
+    if sounds_data.is_empty() {
+        return Ok(());
+    }
+
+    QUEUE_PLAYING.store(true, Ordering::SeqCst);
+    let generation = engine.generation();
+
+    // Spawn thread to play queue sequentially on primary device
+    let sounds_for_primary = sounds_data.clone();
+    let primary_clone = primary_device.clone();
+    let primary_engine = engine.inner().clone();
+    std::thread::spawn(move || {
+        for (i, (sound_id, file_path, volume, trim, speed)) in sounds_for_primary.iter().enumerate() {
+            if !QUEUE_PLAYING.load(Ordering::SeqCst) {
+                break;
+            }
+
+            // Play on primary device with optional crossfade
+            let fade_duration = if i > 0 && crossfade_ms > 0 {
+                Some(std::time::Duration::from_millis(crossfade_ms as u64))
+            } else {
+                None
+            };
+            let play_generation = PlayGeneration { all: generation, sound: primary_engine.sound_generation(sound_id) };
+            if let Err(e) = primary_engine.play_on_device_with_fade(play_generation, sound_id, file_path, primary_clone.as_deref(), *volume, *trim, fade_duration, *speed) {
+                report_playback_error(sound_id, e);
+            }
+
+            // Small gap between sounds (reduced if crossfade enabled)
+            if crossfade_ms == 0 {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+            }
+        }
+
+        QUEUE_PLAYING.store(false, Ordering::SeqCst);
+        // Clear queue after playing
+        if let Ok(mut queue) = SOUND_QUEUE.lock() {
+            queue.clear();
+        }
+    });
+
+    // Also play on monitor device in parallel (for voice chat output)
+    if let Some(monitor) = monitor_device {
+        if primary_device.as_ref() != Some(&monitor) {
+            let monitor_engine = engine.inner().clone();
+            std::thread::spawn(move || {
+                for (i, (sound_id, file_path, volume, trim, speed)) in sounds_data.iter().enumerate() {
+                    if !QUEUE_PLAYING.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    let fade_duration = if i > 0 && crossfade_ms > 0 {
+                        Some(std::time::Duration::from_millis(crossfade_ms as u64))
+                    } else {
+                        None
+                    };
+                    let play_generation = PlayGeneration { all: generation, sound: monitor_engine.sound_generation(sound_id) };
+                    if let Err(e) = monitor_engine.play_on_device_with_fade(play_generation, sound_id, file_path, Some(&monitor), *volume, *trim, fade_duration, *speed) {
+                        report_playback_error(sound_id, e);
+                    }
+
+                    if crossfade_ms == 0 {
+                        std::thread::sleep(std::time::Duration::from_millis(50));
+                    }
+                }
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+fn is_queue_playing() -> bool {
+    QUEUE_PLAYING.load(Ordering::SeqCst)
+}
+
+static AUDITION_RUNNING: AtomicBool = AtomicBool::new(false);
+// Each pad is only played this long during an audition
+const AUDITION_MAX_SECS: f64 = 4.0;
+
+#[derive(Debug, Clone, Serialize)]
+struct AuditionProgress {
+    #[serde(rename = "soundId")]
+    sound_id: Option<String>,
+    name: Option<String>,
+    index: usize,
+    total: usize,
+    finished: bool,
+}
+
+// Play one pad's trimmed region, cut off after AUDITION_MAX_SECS
+fn play_audition_clip(engine: &Engine, generation: u64, sound: &Sound, device_name: Option<&str>, volume: f32) -> Result<(), PlaybackError> {
+    let source = open_decoder(&sound.file_path)?;
+    let (_stream, stream_handle, format) = open_output_stream_with_format(device_name)?;
+    let sink = Arc::new(Sink::try_new(&stream_handle)
+        .map_err(|e| PlaybackError::Device(format!("Failed to create sink: {}", e)))?);
+    let _playback = engine.track(&sound.id, device_name, volume, &sink);
+    let clip = trim_source(source, Trim::of(sound))
+        .speed(sound.playback_speed.clamp(0.25, 2.0))
+        .take_duration(std::time::Duration::from_secs_f64(AUDITION_MAX_SECS));
+    sink.append(match_stream_format(Box::new(clip), format));
+
+    while !sink.empty() {
+        if engine.stopped_since(generation) {
+            sink.stop();
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+    Ok(())
+}
+
+// Play every pad in board order, one at a time, on the monitor (or the given device) so a
+// board can be checked before going live. Stop-all cancels it. Progress goes out as
+// "audition-progress" events.
+#[tauri::command]
+fn audition_all(
+    app_handle: AppHandle,
+    device: Option<String>,
+    gap_ms: u64,
+    state: State<AppState>,
+    engine: State<Engine>,
+) -> Result<(), String> {
+    let (sounds, device) = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        let mut sounds: Vec<(Sound, f32)> = audio_state
+            .sounds
+            .values()
+            .map(|s| (s.clone(), effective_volume(&audio_state, s)))
+            .collect();
+        sounds.sort_by_key(|(s, _)| s.order);
+        let device = device
+            .or_else(|| audio_state.monitor_device.clone())
+            .or_else(|| active_primary_device(&audio_state));
+        (sounds, device)
     };
+    if sounds.is_empty() {
+        return Err("There are no sounds to audition".to_string());
+    }
+    if AUDITION_RUNNING.swap(true, Ordering::SeqCst) {
+        return Err("An audition is already running".to_string());
+    }
 
-    let sink = Sink::try_new(&stream_handle)
-        .map_err(|e| format!("Failed to create sink: {}", e))?;
+    let engine = engine.inner().clone();
+    let generation = engine.generation();
+    let gap = std::time::Duration::from_millis(gap_ms.min(10_000));
+    std::thread::spawn(move || {
+        let total = sounds.len();
+        for (index, (sound, volume)) in sounds.iter().enumerate() {
+            if engine.stopped_since(generation) {
+                break;
+            }
+            let _ = app_handle.emit_all("audition-progress", AuditionProgress {
+                sound_id: Some(sound.id.clone()),
+                name: Some(sound.name.clone()),
+                index,
+                total,
+                finished: false,
+            });
+            if let Err(e) = play_audition_clip(&engine, generation, sound, device.as_deref(), *volume) {
+                report_playback_error(&sound.id, e);
+            }
 
-    sink.set_volume(volume);
+            // Sleep the gap in slices so a stop-all doesn't wait it out
+            let gap_end = std::time::Instant::now() + gap;
+            while std::time::Instant::now() < gap_end && !engine.stopped_since(generation) {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+            }
+        }
 
-    // Apply trim settings and optional looping
-    let start_secs = start_time.unwrap_or(0.0);
-    let speed = playback_speed.clamp(0.25, 2.0);
+        AUDITION_RUNNING.store(false, Ordering::SeqCst);
+        let _ = app_handle.emit_all("audition-progress", AuditionProgress {
+            sound_id: None,
+            name: None,
+            index: total,
+            total,
+            finished: true,
+        });
+    });
+    Ok(())
+}
 
-    // Macro to apply speed effect
-    macro_rules! with_speed {
-        ($source:expr) => {
-            if (speed - 1.0).abs() > 0.01 {
-                $source.speed(speed)
-            } else {
-                $source.speed(1.0) // No-op but keeps type consistent
+// Convert frontend keybind format to Tauri accelerator format
+fn convert_keybind_to_accelerator(keybind: &str) -> String {
+    keybind
+        .replace("CONTROL", "Ctrl")
+        .replace("SHIFT", "Shift")
+        .replace("ALT", "Alt")
+        .replace("META", "Super")
+        .replace("SPACE", "Space")
+        .replace("ARROWUP", "Up")
+        .replace("ARROWDOWN", "Down")
+        .replace("ARROWLEFT", "Left")
+        .replace("ARROWRIGHT", "Right")
+        .replace("ESCAPE", "Escape")
+        .replace("ENTER", "Return")
+        .replace("BACKSPACE", "Backspace")
+        .replace("DELETE", "Delete")
+        .replace("TAB", "Tab")
+}
+
+// Keybind in the form the hook matcher compares against, so the UI can display it the same way
+#[tauri::command]
+fn normalize_keybind_public(keybind: String) -> String {
+    normalize_keybind(&keybind)
+}
+
+// Accelerator string the keybind is registered under with the GlobalShortcutManager
+#[tauri::command]
+fn keybind_to_accelerator(keybind: String) -> String {
+    convert_keybind_to_accelerator(&keybind)
+}
+
+// Debounce: prevent double triggers within 150ms (from both GlobalShortcutManager and rdev).
+// Returns false when this trigger is a duplicate and should be skipped.
+fn debounce_trigger(key: &str) -> bool {
+    let now = std::time::Instant::now();
+    let mut last_triggers = match LAST_TRIGGER_TIME.lock() {
+        Ok(guard) => guard,
+        Err(_) => return false,
+    };
+
+    if let Some(last_time) = last_triggers.get(key) {
+        if now.duration_since(*last_time).as_millis() < 150 {
+            return false;
+        }
+    }
+    last_triggers.insert(key.to_string(), now);
+    true
+}
+
+// Mute and quick search fire from both keybind systems on one press, so they share the
+// sound debounce. Without it mute would toggle twice and cancel itself out.
+fn trigger_control(action: &str) {
+    if triggers_suspended() || !debounce_trigger(action) {
+        return;
+    }
+    match action {
+        "MUTE" => {
+            toggle_muted();
+        }
+        "QUICK_SEARCH" => focus_search(),
+        _ => {
+            if let Some(group) = action.strip_prefix(SHOW_GROUP_PREFIX) {
+                show_group(group);
+            } else if let (Some(group), Some(app_handle)) = (action.strip_prefix(GROUP_NEXT_PREFIX), APP_HANDLE.get()) {
+                if let Err(e) = step_group(group, &app_handle.state(), &app_handle.state()) {
+                    eprintln!("[Group] Couldn't step '{}': {}", group, e);
+                }
             }
+        }
+    }
+}
+
+// Bring the main window forward and ask the frontend to switch to a group's view
+fn show_group(group: &str) {
+    let Some(app_handle) = APP_HANDLE.get() else {
+        return;
+    };
+    if let Some(window) = app_handle.get_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    let _ = app_handle.emit_all("show-group", group);
+}
+
+// Bring the main window forward and ask the frontend to focus its search box
+fn focus_search() {
+    let Some(app_handle) = APP_HANDLE.get() else {
+        return;
+    };
+    if let Some(window) = app_handle.get_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    let _ = app_handle.emit_all("focus-search", ());
+}
+
+// Play sound by ID using the global app handle
+fn play_sound_by_id(sound_id: String) {
+    trigger_sound(sound_id, false);
+}
+
+// Keybind trigger for a sound, from a press or (rdev only) a release. A sound fires on
+// whichever of the two its trigger_on_release setting asks for.
+fn trigger_sound(sound_id: String, on_release: bool) {
+    if triggers_suspended() {
+        return;
+    }
+
+    // Releases get their own debounce key so the press just before can't swallow them
+    let debounce_key = if on_release { format!("{}:release", sound_id) } else { sound_id.clone() };
+    if !debounce_trigger(&debounce_key) {
+        return;
+    }
+
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let state: State<AppState> = app_handle.state();
+        let audio_state = match state.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+
+        let sound = match audio_state.sounds.get(&sound_id) {
+            Some(s) => s.clone(),
+            None => return,
         };
+
+        if sound.trigger_on_release != on_release
+            || sound
+                .keybind
+                .as_deref()
+                .is_some_and(|k| suppressed_while_typing(k) || blocked_by_modifier_rule(k))
+            || !window_filter_allows(sound.active_window_filter.as_deref())
+        {
+            return;
+        }
+
+        if !PathBuf::from(&sound.file_path).exists() {
+            drop(audio_state);
+            report_playback_error(&sound_id, PlaybackError::File("Sound file not found".to_string()));
+            return;
+        }
+
+        let mut plan = PlaybackPlan::new(&sound, effective_volume(&audio_state, &sound));
+        plan.mono_device = mono_monitor_device(&audio_state);
+        let targets = output_targets(&audio_state);
+        let overlap_mode = audio_state.overlap_mode;
+        let exclusive_members = sound
+            .exclusive_group
+            .as_deref()
+            .map(|group| exclusive_group_members(&audio_state.sounds, group));
+        let max_sounds = audio_state.max_concurrent_sounds;
+        let policy = audio_state.concurrency_policy;
+
+        drop(audio_state);
+
+        let engine: State<Engine> = app_handle.state();
+
+        // Only one sound per exclusive group plays at a time
+        if let Some(ref members) = exclusive_members {
+            engine.stop_sounds(members);
+        }
+
+        if !engine.make_room_for_sound(&sound_id, max_sounds, policy) {
+            return;
+        }
+
+        sound_started(&state, &sound);
+
+        // If overlap mode is disabled, stop all sounds first
+        if !overlap_mode {
+            engine.stop_all();
+        }
+
+        plan.pick_random_offset();
+        engine.queue_playback(&plan, &targets);
+    }
+}
+
+#[tauri::command]
+fn register_sound_keybind(app_handle: AppHandle, sound_id: String, keybind: String) -> Result<Option<String>, String> {
+    // A combo the OS refuses still works through the rdev hook, so that error isn't fatal here
+    let _ = register_sound_shortcut(&app_handle, &sound_id, &keybind)?;
+
+    // Registered either way, but tell the user it won't fire under the current setting
+    if blocked_by_modifier_rule(&keybind) {
+        return Ok(Some(format!(
+            "'{}' has no modifier and will be ignored while \"require modifier\" is on",
+            keybind
+        )));
     }
+    Ok(None)
+}
 
-    if let Some(end_secs) = end_time {
-        if start_secs > 0.0 {
-            // Skip to start time, then take duration until end time
-            let duration = end_secs - start_secs;
-            let trimmed = source
-                .skip_duration(std::time::Duration::from_secs_f64(start_secs))
-                .take_duration(std::time::Duration::from_secs_f64(duration));
-            if loop_mode {
-                // Buffer the source for looping (allows repeat without re-reading file)
-                sink.append(with_speed!(trimmed.buffered().repeat_infinite()));
-            } else {
-                sink.append(with_speed!(trimmed));
-            }
-        } else {
-            // Just take until end time
-            let trimmed = source.take_duration(std::time::Duration::from_secs_f64(end_secs));
-            if loop_mode {
-                sink.append(with_speed!(trimmed.buffered().repeat_infinite()));
-            } else {
-                sink.append(with_speed!(trimmed));
+// Register a sound bind with both systems. The outer error is a registry failure, the
+// inner one is the GlobalShortcutManager rejecting the accelerator.
+fn register_sound_shortcut(app_handle: &AppHandle, sound_id: &str, keybind: &str) -> Result<Result<(), String>, String> {
+    // Register with rdev low-level listener (for games without anti-cheat)
+    {
+        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        registry.retain(|_, v| v != sound_id);
+        registry.insert(keybind.to_string(), sound_id.to_string());
+    }
+
+    // Also register with Tauri GlobalShortcutManager (reliable for normal apps)
+    let accelerator = convert_keybind_to_accelerator(keybind);
+    let mut shortcut_manager = app_handle.global_shortcut_manager();
+    let _ = shortcut_manager.unregister(&accelerator); // Ignore error if not registered
+
+    let id = sound_id.to_string();
+    Ok(shortcut_manager
+        .register(&accelerator, move || {
+            play_sound_by_id(id.clone());
+        })
+        .map_err(|e| e.to_string()))
+}
+
+#[tauri::command]
+fn unregister_sound_keybind(app_handle: AppHandle, keybind: String) -> Result<(), String> {
+    // Unregister from rdev
+    {
+        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        registry.remove(&keybind);
+    }
+
+    // Unregister from GlobalShortcutManager
+    let accelerator = convert_keybind_to_accelerator(&keybind);
+    let mut shortcut_manager = app_handle.global_shortcut_manager();
+    let _ = shortcut_manager.unregister(&accelerator);
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct RebindStatus {
+    keybind: String,
+    // Low-level hook registry
+    rdev: bool,
+    // GlobalShortcutManager
+    shortcut: bool,
+    #[serde(rename = "shortcutError")]
+    shortcut_error: Option<String>,
+}
+
+// Move a sound to a new bind without a window where it has none: the new combo is registered
+// alongside the old one, and the old one is only released (and the change saved) once at
+// least one of the two systems took the new one. Otherwise the old bind stays as it was.
+#[tauri::command]
+fn rebind_sound(
+    app_handle: AppHandle,
+    sound_id: String,
+    new_keybind: String,
+    state: State<AppState>,
+) -> Result<RebindStatus, String> {
+    let keybind = validate_keybind(new_keybind.trim())?;
+    let old = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        let sound = audio_state
+            .sounds
+            .get(&sound_id)
+            .ok_or_else(|| "Sound not found".to_string())?;
+        let ignore = HashSet::from([sound_id.clone()]);
+        if let Some(owner) = find_keybind_conflict(&audio_state, &keybind, &ignore) {
+            return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+        }
+        sound.keybind.clone()
+    };
+    let accelerator = convert_keybind_to_accelerator(&keybind);
+
+    let rdev = KEYBIND_REGISTRY
+        .lock()
+        .map(|mut registry| registry.insert(keybind.clone(), sound_id.clone()))
+        .is_ok();
+    let mut shortcut_manager = app_handle.global_shortcut_manager();
+    let _ = shortcut_manager.unregister(&accelerator);
+    let id = sound_id.clone();
+    let shortcut_error = shortcut_manager
+        .register(&accelerator, move || play_sound_by_id(id.clone()))
+        .err()
+        .map(|e| e.to_string());
+    if !rdev {
+        if let Some(e) = shortcut_error {
+            return Err(format!("Couldn't register '{}': {}", keybind, e));
+        }
+    }
+
+    // Persist, undoing the new registration if the sound went away meanwhile
+    let saved = match state.lock() {
+        Ok(mut audio_state) => match audio_state.sounds.get_mut(&sound_id) {
+            Some(sound) => {
+                sound.keybind = Some(keybind.clone());
+                save_sounds(&audio_state.sounds);
+                Ok(())
             }
+            None => Err("Sound not found".to_string()),
+        },
+        Err(e) => Err(e.to_string()),
+    };
+    if let Err(e) = saved {
+        if let Ok(mut registry) = KEYBIND_REGISTRY.lock() {
+            registry.remove(&keybind);
         }
-    } else if start_secs > 0.0 {
-        // Just skip to start time
-        let trimmed = source.skip_duration(std::time::Duration::from_secs_f64(start_secs));
-        if loop_mode {
-            sink.append(with_speed!(trimmed.buffered().repeat_infinite()));
-        } else {
-            sink.append(with_speed!(trimmed));
+        let _ = shortcut_manager.unregister(&accelerator);
+        return Err(e);
+    }
+
+    // Release the old bind. The OS shortcut stays if it's the same combo written differently.
+    if let Some(old) = old.filter(|old| *old != keybind) {
+        if let Ok(mut registry) = KEYBIND_REGISTRY.lock() {
+            if registry.get(&old) == Some(&sound_id) {
+                registry.remove(&old);
+            }
         }
-    } else {
-        // No trimming
-        if loop_mode {
-            sink.append(with_speed!(source.buffered().repeat_infinite()));
-        } else {
-            sink.append(with_speed!(source));
+        let old_accelerator = convert_keybind_to_accelerator(&old);
+        if old_accelerator != accelerator {
+            let _ = shortcut_manager.unregister(&old_accelerator);
         }
     }
 
-    // Poll for stop signal instead of blocking until end
-    while !sink.empty() {
-        if STOP_ALL_FLAG.load(Ordering::SeqCst) {
-            sink.stop();
-            return Ok(());
+    Ok(RebindStatus {
+        keybind,
+        rdev,
+        shortcut: shortcut_error.is_none(),
+        shortcut_error,
+    })
+}
+
+#[tauri::command]
+fn register_stop_all_keybind(app_handle: AppHandle, keybind: String) -> Result<(), String> {
+    // Register with rdev
+    {
+        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        registry.retain(|_, v| v != "STOP_ALL");
+        registry.insert(keybind.clone(), "STOP_ALL".to_string());
+    }
+
+    // Also register with GlobalShortcutManager
+    let accelerator = convert_keybind_to_accelerator(&keybind);
+    let mut shortcut_manager = app_handle.global_shortcut_manager();
+    let _ = shortcut_manager.unregister(&accelerator);
+
+    let _ = shortcut_manager.register(&accelerator, || {
+        if !triggers_suspended() {
+            stop_all_playback(StopAllSource::Shortcut);
         }
-        std::thread::sleep(std::time::Duration::from_millis(50));
-    }
+    });
 
     Ok(())
 }
 
-// Play bass-boosted version using low-pass filter
-fn play_bass_boost(
-    file_path: &str,
-    device_name: Option<&str>,
-    volume: f32,
-    start_time: Option<f64>,
-    end_time: Option<f64>,
-    playback_speed: f32,
-) -> Result<(), String> {
-    let file = File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?;
-    let source = Decoder::new(BufReader::new(file))
-        .map_err(|e| format!("Failed to decode audio: {}", e))?
-        .convert_samples::<f32>(); // Convert to f32 for low_pass filter
+#[tauri::command]
+fn unregister_stop_all_keybind(app_handle: AppHandle, keybind: String) -> Result<(), String> {
+    // Unregister from rdev
+    {
+        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        registry.remove(&keybind);
+    }
 
-    let (_stream, stream_handle): (OutputStream, OutputStreamHandle) = if let Some(name) = device_name {
-        if let Some(device) = find_device_by_name(name) {
-            OutputStream::try_from_device(&device)
-                .map_err(|e| format!("Failed to open device: {}", e))?
-        } else {
-            OutputStream::try_default()
-                .map_err(|e| format!("Failed to open default device: {}", e))?
-        }
-    } else {
-        OutputStream::try_default()
-            .map_err(|e| format!("Failed to open default device: {}", e))?
-    };
+    // Unregister from GlobalShortcutManager
+    let accelerator = convert_keybind_to_accelerator(&keybind);
+    let mut shortcut_manager = app_handle.global_shortcut_manager();
+    let _ = shortcut_manager.unregister(&accelerator);
 
-    let sink = Sink::try_new(&stream_handle)
-        .map_err(|e| format!("Failed to create sink: {}", e))?;
+    Ok(())
+}
 
-    sink.set_volume(volume);
+#[tauri::command]
+fn toggle_mute(engine: State<Engine>) -> bool {
+    engine.toggle_muted()
+}
 
-    let start_secs = start_time.unwrap_or(0.0);
-    let speed = playback_speed.clamp(0.25, 2.0);
+#[tauri::command]
+fn is_muted(engine: State<Engine>) -> bool {
+    engine.is_muted()
+}
 
-    // Apply low-pass filter at 150Hz to isolate bass frequencies, then apply speed
-    macro_rules! with_bass_and_speed {
-        ($source:expr) => {{
-            let bass = $source.low_pass(150);
-            if (speed - 1.0).abs() > 0.01 {
-                bass.speed(speed)
-            } else {
-                bass.speed(1.0)
-            }
-        }};
+fn register_mute_shortcut(app_handle: &AppHandle, keybind: &str) -> Result<(), String> {
+    {
+        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        registry.retain(|_, v| v != "MUTE");
+        registry.insert(keybind.to_string(), "MUTE".to_string());
     }
 
-    if let Some(end_secs) = end_time {
-        if start_secs > 0.0 {
-            let duration = end_secs - start_secs;
-            let trimmed = source
-                .skip_duration(std::time::Duration::from_secs_f64(start_secs))
-                .take_duration(std::time::Duration::from_secs_f64(duration));
-            sink.append(with_bass_and_speed!(trimmed));
-        } else {
-            let trimmed = source.take_duration(std::time::Duration::from_secs_f64(end_secs));
-            sink.append(with_bass_and_speed!(trimmed));
-        }
-    } else if start_secs > 0.0 {
-        let trimmed = source.skip_duration(std::time::Duration::from_secs_f64(start_secs));
-        sink.append(with_bass_and_speed!(trimmed));
-    } else {
-        sink.append(with_bass_and_speed!(source));
-    }
+    let accelerator = convert_keybind_to_accelerator(keybind);
+    let mut shortcut_manager = app_handle.global_shortcut_manager();
+    let _ = shortcut_manager.unregister(&accelerator);
+    let _ = shortcut_manager.register(&accelerator, || trigger_control("MUTE"));
+    Ok(())
+}
 
-    // Poll for stop signal
-    while !sink.empty() {
-        if STOP_ALL_FLAG.load(Ordering::SeqCst) {
-            sink.stop();
-            return Ok(());
-        }
-        std::thread::sleep(std::time::Duration::from_millis(50));
+fn register_quick_search_shortcut(app_handle: &AppHandle, keybind: &str) -> Result<(), String> {
+    {
+        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        registry.retain(|_, v| v != "QUICK_SEARCH");
+        registry.insert(keybind.to_string(), "QUICK_SEARCH".to_string());
     }
 
+    let accelerator = convert_keybind_to_accelerator(keybind);
+    let mut shortcut_manager = app_handle.global_shortcut_manager();
+    let _ = shortcut_manager.unregister(&accelerator);
+    let _ = shortcut_manager.register(&accelerator, || trigger_control("QUICK_SEARCH"));
     Ok(())
 }
 
-// Play extreme bass version - heavily distorted bass effect
-// Stacked low-pass filters + high amplification, replaces original audio
-fn play_fake_bass_boost(
-    file_path: &str,
-    device_name: Option<&str>,
-    volume: f32,
-    start_time: Option<f64>,
-    end_time: Option<f64>,
-    playback_speed: f32,
-) -> Result<(), String> {
-    let file = File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?;
-    let source = Decoder::new(BufReader::new(file))
-        .map_err(|e| format!("Failed to decode audio: {}", e))?
-        .convert_samples::<f32>();
+fn register_group_step_shortcut(app_handle: &AppHandle, group: &str, keybind: &str) -> Result<(), String> {
+    register_group_shortcut(app_handle, format!("{}{}", GROUP_NEXT_PREFIX, group), keybind)
+}
 
-    let (_stream, stream_handle): (OutputStream, OutputStreamHandle) = if let Some(name) = device_name {
-        if let Some(device) = find_device_by_name(name) {
-            OutputStream::try_from_device(&device)
-                .map_err(|e| format!("Failed to open device: {}", e))?
-        } else {
-            OutputStream::try_default()
-                .map_err(|e| format!("Failed to open default device: {}", e))?
-        }
-    } else {
-        OutputStream::try_default()
-            .map_err(|e| format!("Failed to open default device: {}", e))?
-    };
-
-    let sink = Sink::try_new(&stream_handle)
-        .map_err(|e| format!("Failed to create sink: {}", e))?;
-
-    sink.set_volume(volume);
-
-    let start_secs = start_time.unwrap_or(0.0);
-    // Slow it down slightly for that deep fried effect
-    let speed = (playback_speed * 0.85).clamp(0.2, 2.0);
-
-    // MAXIMUM BASS: Stack multiple low-pass filters + extreme amplification
-    // This creates the classic "earrape" distorted bass meme sound
-    macro_rules! with_fake_bass_and_speed {
-        ($source:expr) => {{
-            // Chain: low_pass(600) -> amplify 4x -> low_pass(300) -> amplify 4x
-            // Double filtering + double amplification = pure distorted bass destruction
-            let bass = $source
-                .low_pass(600)      // First pass: capture bass + low-mids
-                .amplify(4.0)       // Boost hard
-                .low_pass(300)      // Second pass: isolate the BASS
-                .amplify(4.0);      // BOOST HARDER (total 16x amplification)
-            bass.speed(speed)
-        }};
-    }
-
-    if let Some(end_secs) = end_time {
-        if start_secs > 0.0 {
-            let duration = end_secs - start_secs;
-            let trimmed = source
-                .skip_duration(std::time::Duration::from_secs_f64(start_secs))
-                .take_duration(std::time::Duration::from_secs_f64(duration));
-            sink.append(with_fake_bass_and_speed!(trimmed));
-        } else {
-            let trimmed = source.take_duration(std::time::Duration::from_secs_f64(end_secs));
-            sink.append(with_fake_bass_and_speed!(trimmed));
-        }
-    } else if start_secs > 0.0 {
-        let trimmed = source.skip_duration(std::time::Duration::from_secs_f64(start_secs));
-        sink.append(with_fake_bass_and_speed!(trimmed));
-    } else {
-        sink.append(with_fake_bass_and_speed!(source));
-    }
+fn register_group_show_shortcut(app_handle: &AppHandle, group: &str, keybind: &str) -> Result<(), String> {
+    register_group_shortcut(app_handle, format!("{}{}", SHOW_GROUP_PREFIX, group), keybind)
+}
 
-    // Poll for stop signal
-    while !sink.empty() {
-        if STOP_ALL_FLAG.load(Ordering::SeqCst) {
-            sink.stop();
-            return Ok(());
-        }
-        std::thread::sleep(std::time::Duration::from_millis(50));
+fn register_group_shortcut(app_handle: &AppHandle, action: String, keybind: &str) -> Result<(), String> {
+    {
+        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        registry.retain(|_, v| *v != action);
+        registry.insert(keybind.to_string(), action.clone());
     }
 
+    let accelerator = convert_keybind_to_accelerator(keybind);
+    let mut shortcut_manager = app_handle.global_shortcut_manager();
+    let _ = shortcut_manager.unregister(&accelerator);
+    let _ = shortcut_manager.register(&accelerator, move || trigger_control(&action));
     Ok(())
 }
 
-// Version with optional fade-in for crossfade support in queue mode
-fn play_on_device_with_fade(
-    file_path: &str,
-    device_name: Option<&str>,
-    volume: f32,
-    start_time: Option<f64>,
-    end_time: Option<f64>,
-    fade_duration: Option<std::time::Duration>,
-    playback_speed: f32,
-) -> Result<(), String> {
-    let file = File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?;
-    let source = Decoder::new(BufReader::new(file))
-        .map_err(|e| format!("Failed to decode audio: {}", e))?;
+// Set (or clear) the keybind that shows the window and focuses search
+#[tauri::command]
+fn set_quick_search_keybind(app_handle: AppHandle, keybind: Option<String>, state: State<AppState>) -> Result<(), String> {
+    let old = {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        if let Some(ref keybind) = keybind {
+            let ignore = HashSet::from(["QUICK_SEARCH".to_string()]);
+            if let Some(owner) = find_keybind_conflict(&audio_state, keybind, &ignore) {
+                return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+            }
+        }
+        let old = std::mem::replace(&mut audio_state.quick_search_keybind, keybind.clone());
+        save_settings(&audio_state);
+        old
+    };
 
-    let (_stream, stream_handle): (OutputStream, OutputStreamHandle) = if let Some(name) = device_name {
-        if let Some(device) = find_device_by_name(name) {
-            OutputStream::try_from_device(&device)
-                .map_err(|e| format!("Failed to open device: {}", e))?
-        } else {
-            OutputStream::try_default()
-                .map_err(|e| format!("Failed to open default device: {}", e))?
+    if let Some(old) = old {
+        unregister_sound_keybind(app_handle.clone(), old)?;
+    }
+    if let Some(keybind) = keybind {
+        register_quick_search_shortcut(&app_handle, &keybind)?;
+    }
+    Ok(())
+}
+
+// Set (or clear) the mute keybind, swapping the registration in both keybind systems
+#[tauri::command]
+fn set_mute_keybind(app_handle: AppHandle, keybind: Option<String>, state: State<AppState>) -> Result<(), String> {
+    let old = {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        if let Some(ref keybind) = keybind {
+            let ignore = HashSet::from(["MUTE".to_string()]);
+            if let Some(owner) = find_keybind_conflict(&audio_state, keybind, &ignore) {
+                return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+            }
         }
-    } else {
-        OutputStream::try_default()
-            .map_err(|e| format!("Failed to open default device: {}", e))?
+        let old = std::mem::replace(&mut audio_state.mute_keybind, keybind.clone());
+        save_settings(&audio_state);
+        old
     };
 
-    let sink = Sink::try_new(&stream_handle)
-        .map_err(|e| format!("Failed to create sink: {}", e))?;
+    // Unregistering is the same for every kind of bind
+    if let Some(old) = old {
+        unregister_sound_keybind(app_handle.clone(), old)?;
+    }
+    if let Some(keybind) = keybind {
+        register_mute_shortcut(&app_handle, &keybind)?;
+    }
+    Ok(())
+}
 
-    sink.set_volume(volume);
+#[derive(Debug, Clone, Serialize)]
+struct ShortcutStatus {
+    keybind: String,
+    accelerator: String,
+    // Sound id, or STOP_ALL / MUTE / QUICK_SEARCH / GROUP_NEXT:<group>
+    action: String,
+    registered: bool,
+    error: Option<String>,
+}
 
-    let start_secs = start_time.unwrap_or(0.0);
-    let speed = playback_speed.clamp(0.25, 2.0);
+// Re-register every bind in the registry with the GlobalShortcutManager and report
+// which ones the OS accepted. Binds that fail here may still work through rdev.
+#[tauri::command]
+fn verify_all_shortcuts(app_handle: AppHandle) -> Result<Vec<ShortcutStatus>, String> {
+    let binds: Vec<(String, String)> = {
+        let registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        registry.iter().map(|(k, a)| (k.clone(), a.clone())).collect()
+    };
 
-    // Helper macro to apply fade_in and speed if needed
-    macro_rules! append_with_effects {
-        ($source:expr) => {{
-            let with_speed = if (speed - 1.0).abs() > 0.01 {
-                $source.speed(speed)
-            } else {
-                $source.speed(1.0)
-            };
-            if let Some(fade) = fade_duration {
-                sink.append(with_speed.fade_in(fade));
-            } else {
-                sink.append(with_speed);
+    let mut shortcut_manager = app_handle.global_shortcut_manager();
+    let mut statuses = Vec::new();
+    for (keybind, action) in binds {
+        let accelerator = convert_keybind_to_accelerator(&keybind);
+        let _ = shortcut_manager.unregister(&accelerator);
+
+        let handler_action = action.clone();
+        let result = shortcut_manager.register(&accelerator, move || match handler_action.as_str() {
+            "STOP_ALL" => {
+                if !triggers_suspended() {
+                    stop_all_playback(StopAllSource::Shortcut);
+                }
             }
-        }};
-    }
+            "PANIC" => stop_all_playback(StopAllSource::Panic),
+            "MUTE" | "QUICK_SEARCH" => trigger_control(&handler_action),
+            action if is_group_action(action) => trigger_control(action),
+            sound_id => play_sound_by_id(sound_id.to_string()),
+        });
 
-    if let Some(end_secs) = end_time {
-        if start_secs > 0.0 {
-            let duration = end_secs - start_secs;
-            let trimmed = source
-                .skip_duration(std::time::Duration::from_secs_f64(start_secs))
-                .take_duration(std::time::Duration::from_secs_f64(duration));
-            append_with_effects!(trimmed);
-        } else {
-            let trimmed = source.take_duration(std::time::Duration::from_secs_f64(end_secs));
-            append_with_effects!(trimmed);
-        }
-    } else if start_secs > 0.0 {
-        let trimmed = source.skip_duration(std::time::Duration::from_secs_f64(start_secs));
-        append_with_effects!(trimmed);
-    } else {
-        append_with_effects!(source);
+        statuses.push(ShortcutStatus {
+            keybind,
+            accelerator,
+            action,
+            registered: result.is_ok(),
+            error: result.err().map(|e| e.to_string()),
+        });
     }
+    statuses.sort_by(|a, b| a.keybind.cmp(&b.keybind));
+    Ok(statuses)
+}
 
-    // Poll for stop signal
-    while !sink.empty() {
-        if STOP_ALL_FLAG.load(Ordering::SeqCst) {
-            sink.stop();
-            return Ok(());
-        }
-        std::thread::sleep(std::time::Duration::from_millis(50));
-    }
+#[tauri::command]
+fn get_current_version() -> String {
+    VERSION.to_string()
+}
 
+// Ignore keybind triggers for the given time, or until resume_triggers if no duration
+#[tauri::command]
+fn suspend_triggers(duration_ms: Option<u64>) -> Result<(), String> {
+    let mut resume_at = TRIGGERS_RESUME_AT.lock().map_err(|e| e.to_string())?;
+    *resume_at = duration_ms.map(|ms| std::time::Instant::now() + std::time::Duration::from_millis(ms));
+    TRIGGERS_SUSPENDED.store(true, Ordering::SeqCst);
     Ok(())
 }
 
 #[tauri::command]
-fn play_sound(sound_id: String, state: State<AppState>) -> Result<(), String> {
+fn resume_triggers() {
+    TRIGGERS_SUSPENDED.store(false, Ordering::SeqCst);
+}
+
+#[tauri::command]
+fn are_triggers_suspended() -> bool {
+    triggers_suspended()
+}
+
+#[tauri::command]
+fn get_last_key_press() -> Option<String> {
+    LAST_KEY_PRESS.lock().ok().and_then(|guard| guard.clone())
+}
+
+#[tauri::command]
+fn get_last_playback_error() -> Option<PlaybackErrorReport> {
+    LAST_PLAYBACK_ERROR.lock().ok().and_then(|guard| guard.clone())
+}
+
+#[tauri::command]
+fn get_registered_keybinds() -> Vec<String> {
+    KEYBIND_REGISTRY.lock()
+        .map(|guard| guard.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+// Portable keybind layout, keyed by sound name since ids differ between boards
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct KeybindLayout {
+    sounds: BTreeMap<String, String>,
+    #[serde(rename = "stopAll", default)]
+    stop_all: Option<String>,
+}
+
+#[tauri::command]
+fn export_keybinds(state: State<AppState>) -> Result<String, String> {
     let audio_state = state.lock().map_err(|e| e.to_string())?;
 
-    let sound = audio_state
-        .sounds
-        .get(&sound_id)
-        .ok_or_else(|| "Sound not found".to_string())?
-        .clone();
+    let mut sounds: Vec<&Sound> = audio_state.sounds.values().collect();
+    sounds.sort_by_key(|s| s.order);
 
-    let file_path = sound.file_path.clone();
-    if !PathBuf::from(&file_path).exists() {
-        return Err("Sound file not found".to_string());
+    let mut layout = KeybindLayout {
+        stop_all: audio_state.stop_all_keybind.clone(),
+        ..Default::default()
+    };
+    for sound in sounds {
+        if let Some(ref keybind) = sound.keybind {
+            // First sound wins if several share a name
+            layout.sounds.entry(sound.name.clone()).or_insert_with(|| keybind.clone());
+        }
     }
 
-    let primary_device = audio_state.primary_device.clone();
-    let monitor_device = audio_state.monitor_device.clone();
-    let volume = audio_state.master_volume * sound.volume;
-    let start_time = sound.start_time;
-    let end_time = sound.end_time;
-    let loop_mode = sound.loop_mode;
-    let playback_speed = sound.playback_speed;
-    let echo_delay = sound.echo_delay;
-    let echo_volume = sound.echo_volume;
-    let reverb_decay = sound.reverb_decay;
-    let bass_boost = sound.bass_boost;
-    let fake_bass_boost = sound.fake_bass_boost;
+    serde_json::to_string_pretty(&layout).map_err(|e| e.to_string())
+}
 
-    // Drop the lock before spawning threads
-    drop(audio_state);
+#[derive(Debug, Clone, Serialize, Default)]
+struct KeybindImportReport {
+    // Layout names with no matching sound
+    unmatched: Vec<String>,
+    // Binds that were applied but didn't register (they may still work through rdev)
+    failed: Vec<ItemFailure>,
+}
 
-    // If stop flag was set (by stop_all), wait a moment for threads to stop, then reset
-    if STOP_ALL_FLAG.load(Ordering::SeqCst) {
-        std::thread::sleep(std::time::Duration::from_millis(100));
-        STOP_ALL_FLAG.store(false, Ordering::SeqCst);
-    }
+// Apply a keybind layout by matching sound names (the first sound by order when several share
+// one). Every bind is validated and conflict-checked first; nothing changes if any is rejected.
+#[tauri::command]
+fn import_keybinds(app_handle: AppHandle, json: String, state: State<AppState>) -> Result<KeybindImportReport, String> {
+    let layout: KeybindLayout = serde_json::from_str(&json)
+        .map_err(|e| format!("Invalid keybind layout: {}", e))?;
+
+    let mut report = KeybindImportReport::default();
+    let mut assignments = Vec::new();
+    let mut old_keybinds = Vec::new();
+    let (stop_all, old_stop_all) = {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+
+        let mut sounds: Vec<&Sound> = audio_state.sounds.values().collect();
+        sounds.sort_by_key(|s| s.order);
+        let mut targets = Vec::new();
+        for (name, keybind) in &layout.sounds {
+            match sounds.iter().find(|s| s.name == *name) {
+                Some(sound) => targets.push((sound.id.clone(), name.clone(), keybind.clone())),
+                None => report.unmatched.push(name.clone()),
+            }
+        }
 
-    // If extreme bass is enabled, play that INSTEAD of everything else (exclusive mode)
-    if fake_bass_boost > 0.0 {
-        // Play extreme bass version on primary device
-        let file_path_extreme = file_path.clone();
-        let primary_extreme = primary_device.clone();
-        let extreme_vol = volume * fake_bass_boost;
-        std::thread::spawn(move || {
-            let _ = play_fake_bass_boost(&file_path_extreme, primary_extreme.as_deref(), extreme_vol, start_time, end_time, playback_speed);
-        });
+        // Binds being replaced don't count as conflicts
+        let mut rebinding: HashSet<String> = targets.iter().map(|(id, _, _)| id.clone()).collect();
+        if layout.stop_all.is_some() {
+            rebinding.insert("STOP_ALL".to_string());
+        }
 
-        // Play extreme bass on monitor device too
-        if let Some(ref monitor) = monitor_device {
-            if primary_device.as_ref() != Some(monitor) {
-                let file_path_extreme = file_path;
-                let monitor_extreme = monitor.clone();
-                let extreme_vol = volume * fake_bass_boost;
-                std::thread::spawn(move || {
-                    let _ = play_fake_bass_boost(&file_path_extreme, Some(&monitor_extreme), extreme_vol, start_time, end_time, playback_speed);
-                });
+        let mut seen = HashSet::new();
+        let stop_all = match layout.stop_all.as_deref() {
+            Some(keybind) => {
+                let keybind = validate_keybind(keybind)?;
+                if let Some(owner) = find_keybind_conflict(&audio_state, &keybind, &rebinding) {
+                    return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+                }
+                seen.insert(keybind.clone());
+                Some(keybind)
+            }
+            None => None,
+        };
+        for (sound_id, name, keybind) in targets {
+            let keybind = validate_keybind(&keybind).map_err(|e| format!("{}: {}", name, e))?;
+            if !seen.insert(keybind.clone()) {
+                return Err(format!("Keybind '{}' is listed more than once", keybind));
+            }
+            if let Some(owner) = find_keybind_conflict(&audio_state, &keybind, &rebinding) {
+                return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
             }
+            assignments.push((sound_id, keybind));
         }
 
-        // Extreme bass is exclusive - skip all other effects
-        return Ok(());
+        for (sound_id, keybind) in &assignments {
+            if let Some(sound) = audio_state.sounds.get_mut(sound_id) {
+                if let Some(old) = sound.keybind.replace(keybind.clone()) {
+                    old_keybinds.push(old);
+                }
+            }
+        }
+        let old_stop_all = match stop_all {
+            Some(ref keybind) => audio_state.stop_all_keybind.replace(keybind.clone()),
+            None => None,
+        };
+
+        save_sounds(&audio_state.sounds);
+        save_settings(&audio_state);
+        (stop_all, old_stop_all)
+    };
+
+    // Unregister everything being replaced before registering, so a bind moving
+    // between sounds isn't removed after its new owner registered it. From here on
+    // the saved state is final, so failures are reported rather than aborting halfway.
+    for keybind in old_keybinds {
+        if let Err(e) = unregister_sound_keybind(app_handle.clone(), keybind.clone()) {
+            report.failed.push(ItemFailure { entry: keybind, reason: e });
+        }
+    }
+    if let Some(keybind) = old_stop_all {
+        if let Err(e) = unregister_stop_all_keybind(app_handle.clone(), keybind.clone()) {
+            report.failed.push(ItemFailure { entry: keybind, reason: e });
+        }
     }
 
-    // Play normal sound to primary device
-    let file_path_primary = file_path.clone();
-    let primary = primary_device.clone();
-    let start_primary = start_time;
-    let end_primary = end_time;
-    std::thread::spawn(move || {
-        let _ = play_on_device(&file_path_primary, primary.as_deref(), volume, start_primary, end_primary, loop_mode, playback_speed);
-    });
+    for (sound_id, keybind) in assignments {
+        match register_sound_shortcut(&app_handle, &sound_id, &keybind) {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) | Err(e) => report.failed.push(ItemFailure { entry: keybind, reason: e }),
+        }
+    }
+    if let Some(keybind) = stop_all {
+        if let Err(e) = register_stop_all_keybind(app_handle.clone(), keybind.clone()) {
+            report.failed.push(ItemFailure { entry: keybind, reason: e });
+        }
+    }
 
-    // Play echo/reverb on primary device (delayed playback at lower volume)
-    // If reverb_decay > 0, create multiple echoes with decaying volume
-    if echo_delay > 0.0 && echo_volume > 0.0 {
-        let num_echoes = if reverb_decay > 0.0 { 5 } else { 1 };
-        for i in 0..num_echoes {
-            let file_path_echo = file_path.clone();
-            let primary_echo = primary_device.clone();
-            let echo_num = i + 1;
-            let delay = echo_delay * echo_num as f32;
-            let decay_factor = if reverb_decay > 0.0 {
-                reverb_decay.powf(echo_num as f32)
-            } else {
-                1.0
-            };
-            let echo_vol = volume * echo_volume * decay_factor;
+    Ok(report)
+}
 
-            // Stop spawning if volume becomes negligible
-            if echo_vol < 0.01 {
-                break;
-            }
+#[derive(Debug, Clone, Serialize, Default)]
+struct ImportReport {
+    imported: Vec<String>,
+    failed: Vec<ItemFailure>,
+}
 
-            std::thread::spawn(move || {
-                std::thread::sleep(std::time::Duration::from_secs_f32(delay));
-                if !STOP_ALL_FLAG.load(Ordering::SeqCst) {
-                    let _ = play_on_device(&file_path_echo, primary_echo.as_deref(), echo_vol, start_time, end_time, false, playback_speed);
-                }
-            });
-        }
+fn keybind_in_use(state: &AudioState, keybind: &str) -> bool {
+    find_keybind_conflict(state, keybind, &HashSet::new()).is_some()
+}
+
+// Friendly name for a registry sentinel, None for sound ids
+fn reserved_action_name(action: &str) -> Option<&'static str> {
+    match action {
+        "STOP_ALL" => Some("Stop All"),
+        "PANIC" => Some("Panic Stop"),
+        "MUTE" => Some("Mute"),
+        "QUICK_SEARCH" => Some("Quick Search"),
+        _ => None,
     }
+}
 
-    // Play to monitor device in a thread (if set and different from primary)
-    if let Some(ref monitor) = monitor_device {
-        if primary_device.as_ref() != Some(monitor) {
-            // Play normal sound to monitor device
-            let file_path_monitor = file_path.clone();
-            let monitor_clone = monitor.clone();
-            std::thread::spawn(move || {
-                let _ = play_on_device(&file_path_monitor, Some(&monitor_clone), volume, start_time, end_time, loop_mode, playback_speed);
-            });
+// Name of whatever already owns a keybind, ignoring the given sounds (e.g. ones being rebound).
+// Sentinels like "STOP_ALL" in `ignore` skip that reserved bind.
+fn find_keybind_conflict(state: &AudioState, keybind: &str, ignore: &HashSet<String>) -> Option<String> {
+    let normalized = normalize_keybind(keybind);
+    let matches = |k: &Option<String>| k.as_deref().is_some_and(|k| normalize_keybind(k) == normalized);
+
+    let reserved = [
+        ("PANIC", Some(PANIC_KEYBIND.to_string())),
+        ("STOP_ALL", state.stop_all_keybind.clone()),
+        ("MUTE", state.mute_keybind.clone()),
+        ("QUICK_SEARCH", state.quick_search_keybind.clone()),
+    ];
+    for (action, bind) in &reserved {
+        if !ignore.contains(*action) && matches(bind) {
+            return reserved_action_name(action).map(str::to_string);
+        }
+    }
+    for (group, bind) in &state.group_step_keybinds {
+        if !ignore.contains(&format!("{}{}", GROUP_NEXT_PREFIX, group)) && matches(&Some(bind.clone())) {
+            return Some(format!("Next in {}", group));
+        }
+    }
+    for (group, bind) in &state.group_show_keybinds {
+        if !ignore.contains(&format!("{}{}", SHOW_GROUP_PREFIX, group)) && matches(&Some(bind.clone())) {
+            return Some(format!("Show {}", group));
+        }
+    }
+    state
+        .sounds
+        .values()
+        .find(|s| !ignore.contains(&s.id) && matches(&s.keybind))
+        .map(|s| s.name.clone())
+}
 
-            // Play echo/reverb on monitor device
-            if echo_delay > 0.0 && echo_volume > 0.0 {
-                let num_echoes = if reverb_decay > 0.0 { 5 } else { 1 };
-                for i in 0..num_echoes {
-                    let file_path_echo = file_path.clone();
-                    let monitor_echo = monitor.clone();
-                    let echo_num = i + 1;
-                    let delay = echo_delay * echo_num as f32;
-                    let decay_factor = if reverb_decay > 0.0 {
-                        reverb_decay.powf(echo_num as f32)
-                    } else {
-                        1.0
-                    };
-                    let echo_vol = volume * echo_volume * decay_factor;
+// Attributes of every <Sound .../> element in a Soundpad sound list
+fn parse_soundpad_entries(xml: &str) -> Vec<HashMap<String, String>> {
+    let mut entries = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find("<Sound ") {
+        rest = &rest[start + "<Sound ".len()..];
+        let end = rest.find('>').unwrap_or(rest.len());
+        let mut attributes = HashMap::new();
+        let mut tag = rest[..end].trim_end_matches('/');
+        while let Some(eq) = tag.find("=\"") {
+            let key = tag[..eq].trim().to_lowercase();
+            let value_start = eq + 2;
+            let Some(value_len) = tag[value_start..].find('"') else {
+                break;
+            };
+            let value = tag[value_start..value_start + value_len]
+                .replace("&quot;", "\"")
+                .replace("&apos;", "'")
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&amp;", "&");
+            attributes.insert(key, value);
+            tag = &tag[value_start + value_len + 1..];
+        }
+        entries.push(attributes);
+        rest = &rest[end..];
+    }
+    entries
+}
 
-                    if echo_vol < 0.01 {
-                        break;
+// Convert a Soundpad hotkey (e.g. "CONTROL+ALT+NUM 1") to our normalized "Alt+Ctrl+NUMPAD1"
+// form, or say why it can't be bound here
+fn convert_soundpad_hotkey(hotkey: &str) -> Result<String, String> {
+    let mut modifiers = Vec::new();
+    let mut main_key = None;
+    for token in hotkey.split('+').map(|t| t.trim().to_uppercase()) {
+        match token.as_str() {
+            "" => {}
+            "CTRL" | "CONTROL" => modifiers.push("Ctrl"),
+            "ALT" | "MENU" => modifiers.push("Alt"),
+            "SHIFT" => modifiers.push("Shift"),
+            "WIN" | "LWIN" | "RWIN" | "META" | "SUPER" => modifiers.push("Super"),
+            key => {
+                let compact = key.replace(' ', "");
+                let mapped = match compact.as_str() {
+                    "RETURN" => "ENTER".to_string(),
+                    "ESC" => "ESCAPE".to_string(),
+                    "PGUP" | "PRIOR" => "PAGEUP".to_string(),
+                    "PGDN" | "NEXT" => "PAGEDOWN".to_string(),
+                    "UP" => "ARROWUP".to_string(),
+                    "DOWN" => "ARROWDOWN".to_string(),
+                    "LEFT" => "ARROWLEFT".to_string(),
+                    "RIGHT" => "ARROWRIGHT".to_string(),
+                    "DEL" => "DELETE".to_string(),
+                    "INS" => "INSERT".to_string(),
+                    // The hook doesn't tell numpad Enter from the main one
+                    "NUMENTER" => "ENTER".to_string(),
+                    k if k.len() > 3
+                        && k.starts_with("NUM")
+                        && k[3..].chars().all(|c| c.is_ascii_digit() || "+-*/".contains(c)) =>
+                    {
+                        format!("NUMPAD{}", &k[3..])
                     }
-
-                    std::thread::spawn(move || {
-                        std::thread::sleep(std::time::Duration::from_secs_f32(delay));
-                        if !STOP_ALL_FLAG.load(Ordering::SeqCst) {
-                            let _ = play_on_device(&file_path_echo, Some(&monitor_echo), echo_vol, start_time, end_time, false, playback_speed);
-                        }
-                    });
-                }
+                    k if k.starts_with("NUM") && k.len() > 3 => {
+                        return Err(format!("Unsupported key '{}' in hotkey '{}'", key, hotkey));
+                    }
+                    k => k.to_string(),
+                };
+                main_key = Some(mapped);
             }
         }
     }
+    modifiers.sort();
+    modifiers.dedup();
+    let mut parts: Vec<String> = modifiers.into_iter().map(String::from).collect();
+    parts.push(main_key.ok_or_else(|| format!("Hotkey '{}' has no key", hotkey))?);
+    validate_keybind(&parts.join("+"))
+}
+
+// Import a Soundpad sound list (.spl), copying each file into the managed library
+#[tauri::command]
+fn import_soundpad(app_handle: AppHandle, file_path: String, state: State<AppState>) -> Result<ImportReport, String> {
+    let xml = std::fs::read_to_string(&file_path).map_err(|e| format!("Failed to read sound list: {}", e))?;
+    let entries = parse_soundpad_entries(&xml);
+    if entries.is_empty() {
+        return Err("No sounds found in the Soundpad list".to_string());
+    }
 
-    // Play bass boost on primary device (low-pass filtered extra bass layer)
-    if bass_boost > 0.0 {
-        let file_path_bass = file_path.clone();
-        let primary_bass = primary_device.clone();
-        let bass_vol = volume * bass_boost;
-        std::thread::spawn(move || {
-            let _ = play_bass_boost(&file_path_bass, primary_bass.as_deref(), bass_vol, start_time, end_time, playback_speed);
-        });
+    let mut report = ImportReport::default();
+    let mut new_keybinds = Vec::new();
+    {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        for entry in entries {
+            let Some(url) = entry.get("url") else {
+                continue;
+            };
+            let source = PathBuf::from(url);
+            let name = entry
+                .get("title")
+                .filter(|t| !t.is_empty())
+                .cloned()
+                .or_else(|| source.file_stem().and_then(|s| s.to_str()).map(String::from))
+                .unwrap_or_else(|| "Untitled".to_string());
+
+            if !source.exists() {
+                report.failed.push(ItemFailure { entry: name, reason: "File not found".to_string() });
+                continue;
+            }
 
-        // Also play bass boost on monitor device
-        if let Some(ref monitor) = monitor_device {
-            if primary_device.as_ref() != Some(monitor) {
-                let file_path_bass = file_path.clone();
-                let monitor_bass = monitor.clone();
-                std::thread::spawn(move || {
-                    let _ = play_bass_boost(&file_path_bass, Some(&monitor_bass), bass_vol, start_time, end_time, playback_speed);
-                });
+            let order = audio_state.sounds.len() as i32;
+            let mut sound = new_sound(name.clone(), String::new(), order, audio_state.default_sound_volume);
+            match copy_into_library(&source, &sound.id) {
+                Ok(dest) => sound.file_path = dest.to_string_lossy().to_string(),
+                Err(reason) => {
+                    report.failed.push(ItemFailure { entry: name, reason });
+                    continue;
+                }
+            }
+
+            // Binds already taken on this board are dropped rather than stolen. Ones we can't
+            // represent are reported, but the sound itself still comes in.
+            match entry.get("hotkey").filter(|h| !h.is_empty()).map(|h| convert_soundpad_hotkey(h)) {
+                Some(Ok(keybind)) if !keybind_in_use(&audio_state, &keybind) => {
+                    sound.keybind = Some(keybind.clone());
+                    new_keybinds.push((sound.id.clone(), keybind));
+                }
+                Some(Err(e)) => report.failed.push(ItemFailure {
+                    entry: name.clone(),
+                    reason: format!("Imported without its keybind: {}", e),
+                }),
+                _ => {}
             }
+
+            audio_state.sounds.insert(sound.id.clone(), sound);
+            report.imported.push(name);
         }
+        save_sounds(&audio_state.sounds);
     }
 
-    Ok(())
-}
+    for (sound_id, keybind) in new_keybinds {
+        register_sound_keybind(app_handle.clone(), sound_id, keybind)?;
+    }
 
-#[tauri::command]
-fn stop_all() -> Result<(), String> {
-    // Set the global stop flag to signal all playing sounds to stop
-    STOP_ALL_FLAG.store(true, Ordering::SeqCst);
-    // Also stop queue playback
-    QUEUE_PLAYING.store(false, Ordering::SeqCst);
-    Ok(())
+    Ok(report)
 }
 
-#[tauri::command]
-fn add_to_queue(sound_id: String) -> Result<Vec<String>, String> {
-    let mut queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?;
-    queue.push(sound_id);
-    Ok(queue.clone())
+// Check a keybind's modifiers and main key, returning it in normalized order
+fn validate_keybind(keybind: &str) -> Result<String, String> {
+    let parts: Vec<&str> = keybind.split('+').map(|p| p.trim()).collect();
+    let (main_key, modifiers) = parts.split_last().ok_or_else(|| "Empty keybind".to_string())?;
+    if main_key.is_empty() {
+        return Err(format!("Keybind '{}' has no key", keybind));
+    }
+    if let Some(bad) = modifiers.iter().find(|m| !["Ctrl", "Alt", "Shift", "Super"].contains(m)) {
+        return Err(format!("Unknown modifier '{}' in keybind '{}'", bad, keybind));
+    }
+    Ok(normalize_keybind(&parts.join("+")))
 }
 
-#[tauri::command]
-fn remove_from_queue(index: usize) -> Result<Vec<String>, String> {
-    let mut queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?;
-    if index < queue.len() {
-        queue.remove(index);
+// Split one CSV line, honoring double-quoted fields with "" escapes
+fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
     }
-    Ok(queue.clone())
+    fields.push(field);
+    fields
 }
 
-#[tauri::command]
-fn clear_queue() -> Result<(), String> {
-    let mut queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?;
-    queue.clear();
-    QUEUE_PLAYING.store(false, Ordering::SeqCst);
-    Ok(())
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
 
-#[tauri::command]
-fn get_queue() -> Result<Vec<String>, String> {
-    let queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?;
-    Ok(queue.clone())
-}
+const CSV_HEADER: &str = "file_path,name,keybind,volume,start,end";
 
-#[tauri::command]
-fn play_queue(state: State<AppState>) -> Result<(), String> {
-    // Don't start if already playing
-    if QUEUE_PLAYING.load(Ordering::SeqCst) {
-        return Ok(());
+// Build a sound from a file_path,name,keybind,volume,start,end row; empty fields use defaults
+fn sound_from_csv_row(fields: &[String], order: i32, default_volume: f32) -> Result<Sound, String> {
+    if fields.len() < 2 || fields.len() > 6 {
+        return Err(format!("Expected 2 to 6 columns, found {}", fields.len()));
     }
+    let field = |i: usize| fields.get(i).map(|f| f.trim()).filter(|f| !f.is_empty());
 
-    let queue = SOUND_QUEUE.lock().map_err(|e| e.to_string())?.clone();
-    if queue.is_empty() {
-        return Ok(());
+    let file_path = field(0).ok_or_else(|| "Missing file path".to_string())?;
+    if !PathBuf::from(file_path).exists() {
+        return Err(format!("File not found: {}", file_path));
     }
+    let name = field(1).ok_or_else(|| "Missing name".to_string())?;
 
-    // Reset stop flag if it was set from a previous stop_all
-    if STOP_ALL_FLAG.load(Ordering::SeqCst) {
-        std::thread::sleep(std::time::Duration::from_millis(100));
-        STOP_ALL_FLAG.store(false, Ordering::SeqCst);
+    let mut sound = new_sound(name.to_string(), file_path.to_string(), order, default_volume);
+    sound.keybind = field(2).map(validate_keybind).transpose()?;
+    if let Some(volume) = field(3) {
+        let volume: f32 = volume.parse().map_err(|_| format!("Invalid volume '{}'", volume))?;
+        sound.volume = volume.clamp(0.0, 2.0); // Same 0-200% range as the editor
     }
-
-    // Get all sound data we need
-    let audio_state = state.lock().map_err(|e| e.to_string())?;
-    let sounds_data: Vec<_> = queue.iter().filter_map(|id| {
-        audio_state.sounds.get(id).map(|s| {
-            (
-                s.file_path.clone(),
-                audio_state.master_volume * s.volume,
-                s.start_time,
-                s.end_time,
-                s.playback_speed,
-            )
-        })
-    }).collect();
-    let primary_device = audio_state.primary_device.clone();
-    let monitor_device = audio_state.monitor_device.clone();
-    let crossfade_ms = audio_state.crossfade_duration;
-    drop(audio_state);
-
-    if sounds_data.is_empty() {
-        return Ok(());
+    let seconds = |i: usize, label: &str| -> Result<Option<f64>, String> {
+        field(i)
+            .map(|v| v.parse::<f64>().map_err(|_| format!("Invalid {} '{}'", label, v)))
+            .transpose()
+    };
+    sound.start_time = seconds(4, "start")?;
+    sound.end_time = seconds(5, "end")?;
+    if let (Some(start), Some(end)) = (sound.start_time, sound.end_time) {
+        if start >= end {
+            return Err("Start must be before end".to_string());
+        }
     }
+    Ok(sound)
+}
 
-    QUEUE_PLAYING.store(true, Ordering::SeqCst);
-
-    // Spawn thread to play queue sequentially on primary device
-    let sounds_for_primary = sounds_data.clone();
-    let primary_clone = primary_device.clone();
-    std::thread::spawn(move || {
-        for (i, (file_path, volume, start_time, end_time, speed)) in sounds_for_primary.iter().enumerate() {
-            if !QUEUE_PLAYING.load(Ordering::SeqCst) {
-                break;
-            }
-
-            // Play on primary device with optional crossfade
-            let fade_duration = if i > 0 && crossfade_ms > 0 {
-                Some(std::time::Duration::from_millis(crossfade_ms as u64))
-            } else {
-                None
-            };
-            let _ = play_on_device_with_fade(file_path, primary_clone.as_deref(), *volume, *start_time, *end_time, fade_duration, *speed);
+// Create sounds from a CSV manifest. Bad rows are skipped and reported by line number.
+#[tauri::command]
+fn import_csv(app_handle: AppHandle, file_path: String, state: State<AppState>) -> Result<ImportReport, String> {
+    let contents = std::fs::read_to_string(&file_path).map_err(|e| format!("Failed to read CSV: {}", e))?;
 
-            // Small gap between sounds (reduced if crossfade enabled)
-            if crossfade_ms == 0 {
-                std::thread::sleep(std::time::Duration::from_millis(50));
+    let mut report = ImportReport::default();
+    let mut new_keybinds = Vec::new();
+    {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            if line.trim().is_empty() || (index == 0 && line.trim().starts_with("file_path")) {
+                continue;
             }
-        }
-
-        QUEUE_PLAYING.store(false, Ordering::SeqCst);
-        // Clear queue after playing
-        if let Ok(mut queue) = SOUND_QUEUE.lock() {
-            queue.clear();
-        }
-    });
 
-    // Also play on monitor device in parallel (for voice chat output)
-    if let Some(monitor) = monitor_device {
-        if primary_device.as_ref() != Some(&monitor) {
-            std::thread::spawn(move || {
-                for (i, (file_path, volume, start_time, end_time, speed)) in sounds_data.iter().enumerate() {
-                    if !QUEUE_PLAYING.load(Ordering::SeqCst) {
-                        break;
+            let order = audio_state.sounds.len() as i32;
+            let result = sound_from_csv_row(&parse_csv_line(line), order, audio_state.default_sound_volume).and_then(|sound| {
+                match sound.keybind {
+                    Some(ref keybind) if keybind_in_use(&audio_state, keybind) => {
+                        Err(format!("Keybind '{}' is already in use", keybind))
                     }
+                    _ => Ok(sound),
+                }
+            });
 
-                    let fade_duration = if i > 0 && crossfade_ms > 0 {
-                        Some(std::time::Duration::from_millis(crossfade_ms as u64))
-                    } else {
-                        None
-                    };
-                    let _ = play_on_device_with_fade(file_path, Some(&monitor), *volume, *start_time, *end_time, fade_duration, *speed);
-
-                    if crossfade_ms == 0 {
-                        std::thread::sleep(std::time::Duration::from_millis(50));
+            match result {
+                Ok(sound) => {
+                    if let Some(ref keybind) = sound.keybind {
+                        new_keybinds.push((sound.id.clone(), keybind.clone()));
                     }
+                    report.imported.push(sound.name.clone());
+                    audio_state.sounds.insert(sound.id.clone(), sound);
                 }
-            });
+                Err(reason) => report.failed.push(ItemFailure {
+                    entry: format!("line {}", line_number),
+                    reason,
+                }),
+            }
         }
+        save_sounds(&audio_state.sounds);
     }
 
-    Ok(())
-}
-
-#[tauri::command]
-fn is_queue_playing() -> bool {
-    QUEUE_PLAYING.load(Ordering::SeqCst)
-}
+    for (sound_id, keybind) in new_keybinds {
+        register_sound_keybind(app_handle.clone(), sound_id, keybind)?;
+    }
 
-// Convert frontend keybind format to Tauri accelerator format
-fn convert_keybind_to_accelerator(keybind: &str) -> String {
-    keybind
-        .replace("CONTROL", "Ctrl")
-        .replace("SHIFT", "Shift")
-        .replace("ALT", "Alt")
-        .replace("META", "Super")
-        .replace("SPACE", "Space")
-        .replace("ARROWUP", "Up")
-        .replace("ARROWDOWN", "Down")
-        .replace("ARROWLEFT", "Left")
-        .replace("ARROWRIGHT", "Right")
-        .replace("ESCAPE", "Escape")
-        .replace("ENTER", "Return")
-        .replace("BACKSPACE", "Backspace")
-        .replace("DELETE", "Delete")
-        .replace("TAB", "Tab")
+    Ok(report)
 }
 
-// Play sound by ID using the global app handle
-fn play_sound_by_id(sound_id: String) {
-    // Debounce: prevent double triggers within 150ms (from both GlobalShortcutManager and rdev)
-    {
-        let now = std::time::Instant::now();
-        let mut last_triggers = match LAST_TRIGGER_TIME.lock() {
-            Ok(guard) => guard,
-            Err(_) => return,
-        };
+#[tauri::command]
+fn export_csv(dest_path: String, state: State<AppState>) -> Result<(), String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    let mut sounds: Vec<&Sound> = audio_state.sounds.values().collect();
+    sounds.sort_by_key(|s| s.order);
 
-        if let Some(last_time) = last_triggers.get(&sound_id) {
-            if now.duration_since(*last_time).as_millis() < 150 {
-                return; // Skip duplicate trigger
-            }
-        }
-        last_triggers.insert(sound_id.clone(), now);
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for sound in sounds {
+        let seconds = |t: Option<f64>| t.map(|t| t.to_string()).unwrap_or_default();
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            csv_field(&sound.file_path),
+            csv_field(&sound.name),
+            csv_field(sound.keybind.as_deref().unwrap_or("")),
+            sound.volume,
+            seconds(sound.start_time),
+            seconds(sound.end_time),
+        ));
     }
 
-    if let Some(app_handle) = APP_HANDLE.get() {
-        let state: State<AppState> = app_handle.state();
-        let audio_state = match state.lock() {
-            Ok(s) => s,
-            Err(_) => return,
-        };
+    std::fs::write(&dest_path, csv).map_err(|e| format!("Failed to write CSV: {}", e))
+}
 
-        let sound = match audio_state.sounds.get(&sound_id) {
-            Some(s) => s.clone(),
-            None => return,
-        };
+const STATS_CSV_HEADER: &str = "name,group,session_count,all_time_count,last_played";
 
-        let file_path = sound.file_path.clone();
-        if !PathBuf::from(&file_path).exists() {
-            return;
-        }
+// Play counts for every sound, most played first. last_played is unix seconds.
+#[tauri::command]
+fn export_stats_csv(dest_path: String, state: State<AppState>) -> Result<(), String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    let mut rows: Vec<(&Sound, SoundStats)> = audio_state
+        .sounds
+        .values()
+        .map(|s| (s, audio_state.stats.sounds.get(&s.id).cloned().unwrap_or_default()))
+        .collect();
+    rows.sort_by(|(a, a_stats), (b, b_stats)| {
+        b_stats
+            .all_time_count
+            .cmp(&a_stats.all_time_count)
+            .then(b_stats.session_count.cmp(&a_stats.session_count))
+            .then(a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
 
-        let primary_device = audio_state.primary_device.clone();
-        let monitor_device = audio_state.monitor_device.clone();
-        let volume = audio_state.master_volume * sound.volume;
-        let start_time = sound.start_time;
-        let end_time = sound.end_time;
-        let loop_mode = sound.loop_mode;
-        let playback_speed = sound.playback_speed;
-        let echo_delay = sound.echo_delay;
-        let echo_volume = sound.echo_volume;
-        let reverb_decay = sound.reverb_decay;
-        let bass_boost = sound.bass_boost;
-        let fake_bass_boost = sound.fake_bass_boost;
-        let overlap_mode = audio_state.overlap_mode;
+    let mut csv = String::from(STATS_CSV_HEADER);
+    csv.push('\n');
+    for (sound, stats) in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            csv_field(&sound.name),
+            csv_field(sound.group.as_deref().unwrap_or("")),
+            stats.session_count,
+            stats.all_time_count,
+            stats.last_played.map(|t| t.to_string()).unwrap_or_default(),
+        ));
+    }
 
-        drop(audio_state);
+    std::fs::write(&dest_path, csv).map_err(|e| format!("Failed to write CSV: {}", e))
+}
 
-        // If overlap mode is disabled, stop all sounds first
-        if !overlap_mode {
-            STOP_ALL_FLAG.store(true, Ordering::SeqCst);
-            std::thread::sleep(std::time::Duration::from_millis(50));
-        }
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum CheatsheetFormat {
+    Txt,
+    Html,
+    Csv,
+}
 
-        // Reset stop flag if needed
-        if STOP_ALL_FLAG.load(Ordering::SeqCst) {
-            std::thread::sleep(std::time::Duration::from_millis(100));
-            STOP_ALL_FLAG.store(false, Ordering::SeqCst);
-        }
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
 
-        // If extreme bass is enabled, play that INSTEAD of everything else (exclusive mode)
-        if fake_bass_boost > 0.0 {
-            let file_path_extreme = file_path.clone();
-            let primary_extreme = primary_device.clone();
-            let extreme_vol = volume * fake_bass_boost;
-            std::thread::Builder::new()
-                .name("extreme_bass_player".to_string())
-                .spawn(move || {
-                    std::thread::sleep(std::time::Duration::from_millis(10));
-                    let _ = play_fake_bass_boost(&file_path_extreme, primary_extreme.as_deref(), extreme_vol, start_time, end_time, playback_speed);
-                })
-                .ok();
-
-            // Play extreme bass on monitor device too
-            if let Some(ref monitor) = monitor_device {
-                if primary_device.as_ref() != Some(monitor) {
-                    let file_path_extreme = file_path;
-                    let monitor_extreme = monitor.clone();
-                    std::thread::Builder::new()
-                        .name("monitor_extreme_bass".to_string())
-                        .spawn(move || {
-                            std::thread::sleep(std::time::Duration::from_millis(10));
-                            let _ = play_fake_bass_boost(&file_path_extreme, Some(&monitor_extreme), extreme_vol, start_time, end_time, playback_speed);
-                        })
-                        .ok();
-                }
+// Printable reference card of every bound sound, grouped by group and in board order,
+// followed by the control binds. Only reads state.
+#[tauri::command]
+fn export_cheatsheet(dest_path: String, format: CheatsheetFormat, state: State<AppState>) -> Result<(), String> {
+    // Rows of (name, bind) per group, ungrouped sounds first
+    let (groups, controls) = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        let mut bound: Vec<&Sound> = audio_state.sounds.values().filter(|s| s.keybind.is_some()).collect();
+        bound.sort_by(|a, b| a.group.cmp(&b.group).then(a.order.cmp(&b.order)));
+
+        let mut groups: Vec<(String, Vec<(String, String)>)> = Vec::new();
+        for sound in bound {
+            let group = sound.group.clone().unwrap_or_default();
+            let row = (sound.name.clone(), normalize_keybind(sound.keybind.as_deref().unwrap_or_default()));
+            match groups.last_mut() {
+                Some((last, rows)) if *last == group => rows.push(row),
+                _ => groups.push((group, vec![row])),
             }
-
-            // Extreme bass is exclusive - skip all other effects
-            return;
         }
 
-        // Play normal sound to primary device
-        let file_path_primary = file_path.clone();
-        let primary = primary_device.clone();
-        std::thread::Builder::new()
-            .name("sound_player".to_string())
-            .spawn(move || {
-                std::thread::sleep(std::time::Duration::from_millis(10));
-                let _ = play_on_device(&file_path_primary, primary.as_deref(), volume, start_time, end_time, loop_mode, playback_speed);
+        let reserved = [
+            ("PANIC", Some(PANIC_KEYBIND.to_string())),
+            ("STOP_ALL", audio_state.stop_all_keybind.clone()),
+            ("MUTE", audio_state.mute_keybind.clone()),
+            ("QUICK_SEARCH", audio_state.quick_search_keybind.clone()),
+        ];
+        let controls: Vec<(String, String)> = reserved
+            .iter()
+            .filter_map(|(action, keybind)| {
+                Some((reserved_action_name(action)?.to_string(), normalize_keybind(keybind.as_deref()?)))
             })
-            .ok();
-
-        // Play echo/reverb on primary device
-        if echo_delay > 0.0 && echo_volume > 0.0 {
-            let num_echoes = if reverb_decay > 0.0 { 5 } else { 1 };
-            for i in 0..num_echoes {
-                let file_path_echo = file_path.clone();
-                let primary_echo = primary_device.clone();
-                let echo_num = i + 1;
-                let delay = echo_delay * echo_num as f32;
-                let decay_factor = if reverb_decay > 0.0 {
-                    reverb_decay.powf(echo_num as f32)
-                } else {
-                    1.0
-                };
-                let echo_vol = volume * echo_volume * decay_factor;
+            .collect();
+        (groups, controls)
+    };
+    let sections: Vec<(String, &Vec<(String, String)>)> = groups
+        .iter()
+        .map(|(group, rows)| (if group.is_empty() { "Ungrouped".to_string() } else { group.clone() }, rows))
+        .chain(std::iter::once(("Controls".to_string(), &controls)))
+        .collect();
 
-                if echo_vol < 0.01 {
-                    break;
+    let output = match format {
+        CheatsheetFormat::Txt => {
+            let mut out = String::from("MotoBoard keybinds\n");
+            for (heading, rows) in &sections {
+                let width = rows.iter().map(|(name, _)| name.chars().count()).max().unwrap_or(0);
+                out.push_str(&format!("\n{}\n", heading));
+                for (name, keybind) in rows.iter() {
+                    out.push_str(&format!("  {:<width$}  {}\n", name, keybind, width = width));
                 }
-
-                std::thread::Builder::new()
-                    .name(format!("echo_player_{}", echo_num))
-                    .spawn(move || {
-                        std::thread::sleep(std::time::Duration::from_secs_f32(delay));
-                        if !STOP_ALL_FLAG.load(Ordering::SeqCst) {
-                            let _ = play_on_device(&file_path_echo, primary_echo.as_deref(), echo_vol, start_time, end_time, false, playback_speed);
-                        }
-                    })
-                    .ok();
-            }
-        }
-
-        // Play to monitor device
-        if let Some(ref monitor) = monitor_device {
-            if primary_device.as_ref() != Some(monitor) {
-                // Play normal sound to monitor device
-                let file_path_monitor = file_path.clone();
-                let monitor_clone = monitor.clone();
-                std::thread::Builder::new()
-                    .name("monitor_player".to_string())
-                    .spawn(move || {
-                        std::thread::sleep(std::time::Duration::from_millis(10));
-                        let _ = play_on_device(&file_path_monitor, Some(&monitor_clone), volume, start_time, end_time, loop_mode, playback_speed);
-                    })
-                    .ok();
-
-                // Play echo/reverb on monitor device
-                if echo_delay > 0.0 && echo_volume > 0.0 {
-                    let num_echoes = if reverb_decay > 0.0 { 5 } else { 1 };
-                    for i in 0..num_echoes {
-                        let file_path_echo = file_path.clone();
-                        let monitor_echo = monitor.clone();
-                        let echo_num = i + 1;
-                        let delay = echo_delay * echo_num as f32;
-                        let decay_factor = if reverb_decay > 0.0 {
-                            reverb_decay.powf(echo_num as f32)
-                        } else {
-                            1.0
-                        };
-                        let echo_vol = volume * echo_volume * decay_factor;
-
-                        if echo_vol < 0.01 {
-                            break;
-                        }
-
-                        std::thread::Builder::new()
-                            .name(format!("monitor_echo_{}", echo_num))
-                            .spawn(move || {
-                                std::thread::sleep(std::time::Duration::from_secs_f32(delay));
-                                if !STOP_ALL_FLAG.load(Ordering::SeqCst) {
-                                    let _ = play_on_device(&file_path_echo, Some(&monitor_echo), echo_vol, start_time, end_time, false, playback_speed);
-                                }
-                            })
-                            .ok();
-                    }
+            }
+            out
+        }
+        CheatsheetFormat::Html => {
+            let mut out = String::from(
+                "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>MotoBoard keybinds</title>\n\
+                 <style>body{font-family:sans-serif}table{border-collapse:collapse;margin-bottom:1em}\
+                 td{border:1px solid #ccc;padding:4px 10px}td:last-child{font-family:monospace}</style>\n\
+                 </head><body>\n<h1>MotoBoard keybinds</h1>\n",
+            );
+            for (heading, rows) in &sections {
+                out.push_str(&format!("<h2>{}</h2>\n<table>\n", html_escape(heading)));
+                for (name, keybind) in rows.iter() {
+                    out.push_str(&format!(
+                        "<tr><td>{}</td><td>{}</td></tr>\n",
+                        html_escape(name),
+                        html_escape(keybind)
+                    ));
                 }
+                out.push_str("</table>\n");
             }
+            out.push_str("</body></html>\n");
+            out
         }
-
-        // Play bass boost on primary device
-        if bass_boost > 0.0 {
-            let file_path_bass = file_path.clone();
-            let primary_bass = primary_device.clone();
-            let bass_vol = volume * bass_boost;
-            std::thread::Builder::new()
-                .name("bass_boost".to_string())
-                .spawn(move || {
-                    let _ = play_bass_boost(&file_path_bass, primary_bass.as_deref(), bass_vol, start_time, end_time, playback_speed);
-                })
-                .ok();
-
-            // Also play bass boost on monitor device
-            if let Some(ref monitor) = monitor_device {
-                if primary_device.as_ref() != Some(monitor) {
-                    let file_path_bass = file_path;
-                    let monitor_bass = monitor.clone();
-                    std::thread::Builder::new()
-                        .name("monitor_bass_boost".to_string())
-                        .spawn(move || {
-                            let _ = play_bass_boost(&file_path_bass, Some(&monitor_bass), bass_vol, start_time, end_time, playback_speed);
-                        })
-                        .ok();
+        CheatsheetFormat::Csv => {
+            let mut out = String::from("group,name,keybind\n");
+            for (heading, rows) in &sections {
+                for (name, keybind) in rows.iter() {
+                    out.push_str(&format!("{},{},{}\n", csv_field(heading), csv_field(name), csv_field(keybind)));
                 }
             }
+            out
         }
+    };
+
+    std::fs::write(&dest_path, output).map_err(|e| format!("Failed to write cheat sheet: {}", e))
+}
+
+// Single-sound share bundle: a zip holding the audio file and the sound's settings.
+// The keybind is machine-specific and left out.
+const SOUND_BUNDLE_META: &str = "sound.json";
+
+#[tauri::command]
+fn export_sound(sound_id: String, dest_path: String, state: State<AppState>) -> Result<(), String> {
+    let mut sound = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state
+            .sounds
+            .get(&sound_id)
+            .cloned()
+            .ok_or_else(|| "Sound not found".to_string())?
+    };
+
+    let source = PathBuf::from(&sound.file_path);
+    let audio = std::fs::read(&source).map_err(|e| format!("Failed to read sound file: {}", e))?;
+    let audio_name = match source.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("audio.{}", ext),
+        None => "audio".to_string(),
+    };
+
+    sound.keybind = None;
+    sound.file_path = audio_name.clone();
+    let meta = serde_json::to_string_pretty(&sound).map_err(|e| e.to_string())?;
+
+    let file = File::create(&dest_path).map_err(|e| format!("Failed to create bundle: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::FileOptions::default();
+    zip.start_file(SOUND_BUNDLE_META, options).map_err(|e| e.to_string())?;
+    zip.write_all(meta.as_bytes()).map_err(|e| e.to_string())?;
+    zip.start_file(audio_name, options).map_err(|e| e.to_string())?;
+    zip.write_all(&audio).map_err(|e| e.to_string())?;
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// Add a sound from an export_sound bundle as a new pad at the end of the board
+#[tauri::command]
+fn import_sound_bundle(src_path: String, state: State<AppState>) -> Result<Sound, String> {
+    use std::io::Read;
+
+    let file = File::open(&src_path).map_err(|e| format!("Failed to open bundle: {}", e))?;
+    let mut zip = zip::ZipArchive::new(BufReader::new(file)).map_err(|e| format!("Invalid bundle: {}", e))?;
+
+    let mut sound: Sound = {
+        let meta = zip
+            .by_name(SOUND_BUNDLE_META)
+            .map_err(|_| "Bundle has no sound metadata".to_string())?;
+        serde_json::from_reader(meta).map_err(|e| format!("Invalid sound metadata: {}", e))?
+    };
+
+    // Only the file named in the metadata is read, so paths in the archive never reach the disk
+    let audio_name = std::path::Path::new(&sound.file_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| "Invalid audio file name in bundle".to_string())?
+        .to_string();
+    let mut audio = Vec::new();
+    zip.by_name(&audio_name)
+        .map_err(|_| "Bundle has no audio file".to_string())?
+        .read_to_end(&mut audio)
+        .map_err(|e| e.to_string())?;
+
+    sound.id = Uuid::new_v4().to_string();
+    sound.keybind = None;
+    sound.created_at = unix_now_millis();
+
+    let library_dir = ensure_library_dir().ok_or_else(|| "Could not create sound library".to_string())?;
+    let mut dest = library_dir.join(&sound.id);
+    if let Some(ext) = std::path::Path::new(&audio_name).extension() {
+        dest.set_extension(ext);
     }
+    std::fs::write(&dest, audio).map_err(|e| format!("Failed to write sound file: {}", e))?;
+    sound.file_path = dest.to_string_lossy().to_string();
+
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    sound.order = audio_state
+        .sounds
+        .values()
+        .map(|s| s.order)
+        .max()
+        .map_or(0, |max| max + 1);
+    audio_state.sounds.insert(sound.id.clone(), sound.clone());
+    save_sounds(&audio_state.sounds);
+
+    spawn_clipping_check(&sound);
+    Ok(sound)
+}
+
+// Version of the saved data layout (sounds.json / settings.json and blobs built from them).
+// Bump when a change can't be read by older builds.
+const DATA_SCHEMA_VERSION: u32 = 1;
+
+// Ad-hoc save point of the whole board, taken before a risky bulk edit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Snapshot {
+    version: u32,
+    #[serde(rename = "createdAt")]
+    created_at: u64,
+    sounds: Vec<Sound>,
+    settings: PersistentSettings,
 }
 
 #[tauri::command]
-fn register_sound_keybind(app_handle: AppHandle, sound_id: String, keybind: String) -> Result<(), String> {
-    // Register with rdev low-level listener (for games without anti-cheat)
-    {
-        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
-        registry.retain(|_, v| v != &sound_id);
-        registry.insert(keybind.clone(), sound_id.clone());
+fn create_snapshot(state: State<AppState>) -> Result<String, String> {
+    let audio_state = state.lock().map_err(|e| e.to_string())?;
+    let mut sounds: Vec<Sound> = audio_state.sounds.values().cloned().collect();
+    sounds.sort_by_key(|s| s.order);
+    let snapshot = Snapshot {
+        version: DATA_SCHEMA_VERSION,
+        created_at: unix_now_millis(),
+        sounds,
+        settings: persistent_settings(&audio_state),
+    };
+    serde_json::to_string(&snapshot).map_err(|e| e.to_string())
+}
+
+// Replace the live sounds and settings with a snapshot, swap every keybind over and save.
+// Play stats are kept.
+#[tauri::command]
+fn restore_snapshot(
+    app_handle: AppHandle,
+    blob: String,
+    state: State<AppState>,
+    engine: State<Engine>,
+) -> Result<(), String> {
+    let snapshot: Snapshot = serde_json::from_str(&blob).map_err(|e| format!("Invalid snapshot: {}", e))?;
+    if snapshot.version > DATA_SCHEMA_VERSION {
+        return Err(format!(
+            "Snapshot is from a newer version of MotoBoard (format {}, this build reads up to {})",
+            snapshot.version, DATA_SCHEMA_VERSION
+        ));
+    }
+
+    let sounds = snapshot
+        .sounds
+        .into_iter()
+        .map(|sound| (sound.id.clone(), sound))
+        .collect();
+    replace_board(&app_handle, &state, &engine, sounds, Some(snapshot.settings), true)
+}
+
+// Swap the live sounds (and settings, if given) for new ones and move every keybind over
+fn replace_board(
+    app_handle: &AppHandle,
+    state: &State<AppState>,
+    engine: &State<Engine>,
+    sounds: HashMap<String, Sound>,
+    settings: Option<PersistentSettings>,
+    save: bool,
+) -> Result<(), String> {
+    // Drop every user bind; the reserved panic bind stays
+    let old_keybinds: Vec<String> = {
+        let registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
+        registry
+            .iter()
+            .filter(|(_, action)| action.as_str() != "PANIC")
+            .map(|(keybind, _)| keybind.clone())
+            .collect()
+    };
+    for keybind in old_keybinds {
+        unregister_sound_keybind(app_handle.clone(), keybind)?;
     }
 
-    // Also register with Tauri GlobalShortcutManager (reliable for normal apps)
-    let accelerator = convert_keybind_to_accelerator(&keybind);
-    let mut shortcut_manager = app_handle.global_shortcut_manager();
-    let _ = shortcut_manager.unregister(&accelerator); // Ignore error if not registered
+    let (sound_keybinds, stop_all_keybind, mute_keybind, quick_search_keybind, group_step_keybinds, group_show_keybinds) = {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        audio_state.sounds = sounds;
+        if let Some(settings) = settings {
+            apply_settings(&mut audio_state, settings);
+        }
+        sync_runtime_settings(&audio_state, engine);
+        if save {
+            save_sounds(&audio_state.sounds);
+            save_settings(&audio_state);
+        }
 
-    let id = sound_id.clone();
-    let _ = shortcut_manager.register(&accelerator, move || {
-        play_sound_by_id(id.clone());
-    });
+        let sound_keybinds: Vec<(String, String)> = audio_state
+            .sounds
+            .values()
+            .filter_map(|s| s.keybind.clone().map(|k| (s.id.clone(), k)))
+            .collect();
+        (
+            sound_keybinds,
+            audio_state.stop_all_keybind.clone(),
+            audio_state.mute_keybind.clone(),
+            audio_state.quick_search_keybind.clone(),
+            audio_state.group_step_keybinds.clone(),
+            audio_state.group_show_keybinds.clone(),
+        )
+    };
 
+    for (sound_id, keybind) in sound_keybinds {
+        let _ = register_sound_shortcut(app_handle, &sound_id, &keybind)?;
+    }
+    if let Some(keybind) = stop_all_keybind {
+        register_stop_all_keybind(app_handle.clone(), keybind)?;
+    }
+    if let Some(keybind) = mute_keybind {
+        register_mute_shortcut(app_handle, &keybind)?;
+    }
+    if let Some(keybind) = quick_search_keybind {
+        register_quick_search_shortcut(app_handle, &keybind)?;
+    }
+    for (group, keybind) in group_step_keybinds {
+        register_group_step_shortcut(app_handle, &group, &keybind)?;
+    }
+    for (group, keybind) in group_show_keybinds {
+        register_group_show_shortcut(app_handle, &group, &keybind)?;
+    }
     Ok(())
 }
 
+// Re-read sounds.json, settings.json and stats.json after they were edited or restored by
+// hand, without restarting. Playback is stopped first since sounds may have gone away.
 #[tauri::command]
-fn unregister_sound_keybind(app_handle: AppHandle, keybind: String) -> Result<(), String> {
-    // Unregister from rdev
-    {
-        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
-        registry.remove(&keybind);
+fn reload_from_disk(app_handle: AppHandle, state: State<AppState>, engine: State<Engine>) -> Result<(), String> {
+    if !should_persist() {
+        return Err("Nothing is loaded from disk in this mode".to_string());
+    }
+    let config_dir = get_config_dir().ok_or_else(|| "Could not find the config directory".to_string())?;
+
+    // The loaders fall back to an empty board on bad JSON, which must not wipe the live one
+    let sounds_file = config_dir.join("sounds.json");
+    if sounds_file.exists() {
+        let file = File::open(&sounds_file).map_err(|e| format!("Failed to open sounds.json: {}", e))?;
+        serde_json::from_reader::<_, Vec<Sound>>(BufReader::new(file))
+            .map_err(|e| format!("sounds.json is invalid: {}", e))?;
+    }
+    let settings_file = config_dir.join("settings.json");
+    if settings_file.exists() {
+        let file = File::open(&settings_file).map_err(|e| format!("Failed to open settings.json: {}", e))?;
+        serde_json::from_reader::<_, PersistentSettings>(BufReader::new(file))
+            .map_err(|e| format!("settings.json is invalid: {}", e))?;
     }
 
-    // Unregister from GlobalShortcutManager
-    let accelerator = convert_keybind_to_accelerator(&keybind);
-    let mut shortcut_manager = app_handle.global_shortcut_manager();
-    let _ = shortcut_manager.unregister(&accelerator);
+    stop_all_playback(StopAllSource::Command);
+    replace_board(&app_handle, &state, &engine, load_sounds(), load_settings(), false)?;
+    if let Ok(mut audio_state) = state.lock() {
+        audio_state.stats = load_stats();
+    }
 
+    let _ = app_handle.emit_all("state-reloaded", ());
     Ok(())
 }
 
+// Saved boards other than the live one, one directory each with the same layout as the config dir
+fn get_profiles_dir() -> Option<PathBuf> {
+    get_config_dir().map(|p| p.join("profiles"))
+}
+
+// Fork the current board into profiles/<new_name>: sounds.json, settings.json and its own
+// copies of the library files, so editing either board leaves the other alone. Files outside
+// the library are still referenced where they are. Nothing is registered; the copied binds
+// only apply once the profile is the one loaded.
 #[tauri::command]
-fn register_stop_all_keybind(app_handle: AppHandle, keybind: String) -> Result<(), String> {
-    // Register with rdev
-    {
-        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
-        registry.retain(|_, v| v != "STOP_ALL");
-        registry.insert(keybind.clone(), "STOP_ALL".to_string());
+fn clone_profile(new_name: String, state: State<AppState>) -> Result<(), String> {
+    let name = new_name.trim();
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\', ':']) {
+        return Err(format!("Invalid profile name '{}'", new_name));
+    }
+    let profile_dir = get_profiles_dir()
+        .ok_or_else(|| "Could not find the config directory".to_string())?
+        .join(name);
+    if profile_dir.exists() {
+        return Err(format!("A profile named '{}' already exists", name));
     }
 
-    // Also register with GlobalShortcutManager
-    let accelerator = convert_keybind_to_accelerator(&keybind);
-    let mut shortcut_manager = app_handle.global_shortcut_manager();
-    let _ = shortcut_manager.unregister(&accelerator);
+    let (mut sounds, settings) = {
+        let audio_state = state.lock().map_err(|e| e.to_string())?;
+        let mut sounds: Vec<Sound> = audio_state.sounds.values().cloned().collect();
+        sounds.sort_by_key(|s| s.order);
+        (sounds, persistent_settings(&audio_state))
+    };
 
-    let _ = shortcut_manager.register(&accelerator, || {
-        STOP_ALL_FLAG.store(true, Ordering::SeqCst);
-    });
+    let library_dir = profile_dir.join("sounds");
+    let mut copy = || -> Result<(), String> {
+        std::fs::create_dir_all(&library_dir).map_err(|e| format!("Failed to create profile: {}", e))?;
+        for sound in sounds.iter_mut().filter(|s| is_in_library(&s.file_path)) {
+            let source = PathBuf::from(&sound.file_path);
+            let Some(file_name) = source.file_name() else {
+                continue;
+            };
+            let dest = library_dir.join(file_name);
+            std::fs::copy(&source, &dest).map_err(|e| format!("Failed to copy '{}': {}", sound.name, e))?;
+            sound.file_path = dest.to_string_lossy().to_string();
+        }
 
-    Ok(())
+        let sounds_json = serde_json::to_string_pretty(&sounds).map_err(|e| e.to_string())?;
+        std::fs::write(profile_dir.join("sounds.json"), sounds_json)
+            .map_err(|e| format!("Failed to write sounds: {}", e))?;
+        let settings_json = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+        std::fs::write(profile_dir.join("settings.json"), settings_json)
+            .map_err(|e| format!("Failed to write settings: {}", e))
+    };
+
+    // Don't leave a half-written profile behind to block the name
+    copy().inspect_err(|_| {
+        let _ = std::fs::remove_dir_all(&profile_dir);
+    })
 }
 
+// Bind a run of sounds to a run of keys (e.g. NUMPAD1..NUMPAD9), optionally behind a shared
+// modifier. Nothing is assigned if any bind conflicts. Returns each bind's OS registration result.
 #[tauri::command]
-fn unregister_stop_all_keybind(app_handle: AppHandle, keybind: String) -> Result<(), String> {
-    // Unregister from rdev
-    {
-        let mut registry = KEYBIND_REGISTRY.lock().map_err(|e| e.to_string())?;
-        registry.remove(&keybind);
+fn bulk_assign_keybinds(
+    app_handle: AppHandle,
+    sound_ids: Vec<String>,
+    keys: Vec<String>,
+    modifier: Option<String>,
+    state: State<AppState>,
+) -> Result<Vec<ShortcutStatus>, String> {
+    if keys.len() < sound_ids.len() {
+        return Err(format!("{} sounds but only {} keys", sound_ids.len(), keys.len()));
     }
 
-    // Unregister from GlobalShortcutManager
-    let accelerator = convert_keybind_to_accelerator(&keybind);
-    let mut shortcut_manager = app_handle.global_shortcut_manager();
-    let _ = shortcut_manager.unregister(&accelerator);
+    let mut assignments = Vec::new();
+    let mut old_keybinds = Vec::new();
+    {
+        let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+        let rebinding: HashSet<String> = sound_ids.iter().cloned().collect();
 
-    Ok(())
-}
+        let mut seen = HashSet::new();
+        for (sound_id, key) in sound_ids.iter().zip(keys.iter()) {
+            if !audio_state.sounds.contains_key(sound_id) {
+                return Err(format!("Sound not found: {}", sound_id));
+            }
+            let keybind = match modifier.as_deref().map(str::trim).filter(|m| !m.is_empty()) {
+                Some(modifier) => validate_keybind(&format!("{}+{}", modifier, key.trim()))?,
+                None => validate_keybind(key)?,
+            };
+            if !seen.insert(keybind.clone()) {
+                return Err(format!("Keybind '{}' is listed more than once", keybind));
+            }
+            if let Some(owner) = find_keybind_conflict(&audio_state, &keybind, &rebinding) {
+                return Err(format!("Keybind '{}' is already used by {}", keybind, owner));
+            }
+            assignments.push((sound_id.clone(), keybind));
+        }
 
-#[tauri::command]
-fn get_current_version() -> String {
-    VERSION.to_string()
-}
+        for (sound_id, keybind) in &assignments {
+            if let Some(sound) = audio_state.sounds.get_mut(sound_id) {
+                if let Some(old) = sound.keybind.replace(keybind.clone()) {
+                    old_keybinds.push(old);
+                }
+            }
+        }
+        save_sounds(&audio_state.sounds);
+    }
 
-#[tauri::command]
-fn get_last_key_press() -> Option<String> {
-    LAST_KEY_PRESS.lock().ok().and_then(|guard| guard.clone())
-}
+    for keybind in old_keybinds {
+        unregister_sound_keybind(app_handle.clone(), keybind)?;
+    }
 
-#[tauri::command]
-fn get_registered_keybinds() -> Vec<String> {
-    KEYBIND_REGISTRY.lock()
-        .map(|guard| guard.keys().cloned().collect())
-        .unwrap_or_default()
+    let mut statuses = Vec::new();
+    for (sound_id, keybind) in assignments {
+        let result = register_sound_shortcut(&app_handle, &sound_id, &keybind)?;
+        statuses.push(ShortcutStatus {
+            accelerator: convert_keybind_to_accelerator(&keybind),
+            keybind,
+            action: sound_id,
+            registered: result.is_ok(),
+            error: result.err(),
+        });
+    }
+    Ok(statuses)
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1738,18 +8587,80 @@ struct UpdateInfo {
     notes: Option<String>,
 }
 
+#[tauri::command]
+fn set_auto_check_updates(enabled: bool, state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.auto_check_updates = enabled;
+    save_settings(&audio_state);
+    Ok(())
+}
+
+// Background update check on startup, at most once a day. Emits update-available when there is one.
+fn start_auto_update_check(app_handle: AppHandle) {
+    let state: State<AppState> = app_handle.state();
+    {
+        let Ok(mut audio_state) = state.lock() else {
+            return;
+        };
+        if !audio_state.auto_check_updates {
+            return;
+        }
+        let now = unix_now();
+        if audio_state
+            .last_update_check
+            .is_some_and(|last| now.saturating_sub(last) < 86400)
+        {
+            return;
+        }
+        audio_state.last_update_check = Some(now);
+        save_settings(&audio_state);
+    }
+
+    tauri::async_runtime::spawn(async move {
+        match check_for_updates(app_handle.clone()).await {
+            Ok(info) if info.available => {
+                let _ = app_handle.emit_all("update-available", info);
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("[Updater] Startup check failed: {}", e),
+        }
+    });
+}
+
+#[tauri::command]
+fn set_update_channel(channel: String, state: State<AppState>) -> Result<(), String> {
+    let channel = channel.trim().to_lowercase();
+    if !UPDATE_CHANNELS.contains(&channel.as_str()) {
+        return Err(format!("Unknown update channel: {}", channel));
+    }
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    if audio_state.update_channel != channel {
+        // The last check was against the old channel's feed, so let the next one run right away
+        audio_state.last_update_check = None;
+    }
+    audio_state.update_channel = channel;
+    save_settings(&audio_state);
+    Ok(())
+}
+
 #[tauri::command]
 async fn check_for_updates(app_handle: AppHandle) -> Result<UpdateInfo, String> {
     use tauri::updater::builder;
 
-    println!("[Updater] Checking for updates... Current version: {}", VERSION);
+    let channel = current_update_channel(&app_handle);
+    let endpoints = updater_endpoints(&app_handle, &channel, None);
+    println!("[Updater] Checking for updates ({})... Current version: {}", channel, VERSION);
 
-    match builder(app_handle).check().await {
+    let skipped = skipped_update_versions(&app_handle);
+
+    match builder(app_handle).endpoints(&endpoints).check().await {
         Ok(update) => {
             println!("[Updater] Latest version from server: {}", update.latest_version());
             println!("[Updater] Update available: {}", update.is_update_available());
 
-            if update.is_update_available() {
+            // A skipped version stays hidden until a newer one is published
+            let is_skipped = skipped.contains(&normalize_version(update.latest_version()));
+            if update.is_update_available() && !is_skipped {
                 Ok(UpdateInfo {
                     available: true,
                     version: Some(update.latest_version().to_string()),
@@ -1770,11 +8681,105 @@ async fn check_for_updates(app_handle: AppHandle) -> Result<UpdateInfo, String>
     }
 }
 
+fn normalize_version(version: &str) -> String {
+    version.trim().trim_start_matches('v').to_string()
+}
+
+fn skipped_update_versions(app_handle: &AppHandle) -> Vec<String> {
+    let state: State<AppState> = app_handle.state();
+    let skipped = state
+        .lock()
+        .map(|audio_state| audio_state.skipped_updates.clone())
+        .unwrap_or_default();
+    skipped
+}
+
+#[tauri::command]
+fn skip_update_version(version: String, state: State<AppState>) -> Result<(), String> {
+    let version = normalize_version(&version);
+    if version.is_empty() {
+        return Err("Version cannot be empty".to_string());
+    }
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    if !audio_state.skipped_updates.contains(&version) {
+        audio_state.skipped_updates.push(version);
+        save_settings(&audio_state);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn clear_skipped_updates(state: State<AppState>) -> Result<(), String> {
+    let mut audio_state = state.lock().map_err(|e| e.to_string())?;
+    audio_state.skipped_updates.clear();
+    save_settings(&audio_state);
+    Ok(())
+}
+
+fn current_update_channel(app_handle: &AppHandle) -> String {
+    let state: State<AppState> = app_handle.state();
+    let channel = state
+        .lock()
+        .map(|audio_state| audio_state.update_channel.clone())
+        .unwrap_or_else(|_| default_update_channel());
+    channel
+}
+
+// Updater endpoints from tauri.conf.json, pointed at a specific release when a version is given.
+// The beta feed is the latest.json on the rolling "beta" prerelease tag.
+fn updater_endpoints(app_handle: &AppHandle, channel: &str, version: Option<&str>) -> Vec<String> {
+    let endpoints = app_handle
+        .config()
+        .tauri
+        .updater
+        .endpoints
+        .clone()
+        .unwrap_or_default();
+
+    endpoints
+        .iter()
+        .map(|endpoint| {
+            let url = endpoint.to_string();
+            match version {
+                Some(version) => url.replace(
+                    "/releases/latest/download/",
+                    &format!("/releases/download/v{}/", version.trim_start_matches('v')),
+                ),
+                None if channel == "beta" => {
+                    url.replace("/releases/latest/download/", "/releases/download/beta/")
+                }
+                None => url,
+            }
+        })
+        .collect()
+}
+
+// Fetch release notes for the latest (or a given) version, even if it isn't newer than this build
+#[tauri::command]
+async fn get_release_notes(app_handle: AppHandle, version: Option<String>) -> Result<String, String> {
+    use tauri::updater::builder;
+
+    let channel = current_update_channel(&app_handle);
+    let endpoints = updater_endpoints(&app_handle, &channel, version.as_deref());
+    let update = builder(app_handle)
+        .endpoints(&endpoints)
+        .should_install(|_, _| true)
+        .skip_events()
+        .check()
+        .await
+        .map_err(|e| format!("Failed to fetch release notes: {}", e))?;
+
+    Ok(update.body().cloned().unwrap_or_default())
+}
+
 #[tauri::command]
 async fn install_update(app_handle: AppHandle) -> Result<(), String> {
     use tauri::updater::builder;
 
+    let channel = current_update_channel(&app_handle);
+    let endpoints = updater_endpoints(&app_handle, &channel, None);
     let update = builder(app_handle.clone())
+        .endpoints(&endpoints)
         .check()
         .await
         .map_err(|e| format!("Failed to check for updates: {}", e))?;
@@ -1793,29 +8798,46 @@ async fn install_update(app_handle: AppHandle) -> Result<(), String> {
 }
 
 fn main() {
+    // Safe mode skips the saved board so a bad sounds.json or keybind can't block startup
+    if std::env::args().any(|arg| arg == "--safe-mode") {
+        SAFE_MODE.store(true, Ordering::SeqCst);
+    }
+    if std::env::args().any(|arg| arg == "--debug-resources") {
+        DEBUG_RESOURCES.store(true, Ordering::SeqCst);
+    }
+
     // Load saved data on startup (release builds only)
     let mut initial_state = AudioState::default();
 
     if should_persist() {
         // Load sounds
         initial_state.sounds = load_sounds();
+        initial_state.stats = load_stats();
 
         // Load settings
         if let Some(settings) = load_settings() {
-            initial_state.primary_device = settings.primary_device;
-            initial_state.monitor_device = settings.monitor_device;
-            initial_state.master_volume = settings.master_volume;
-            initial_state.stop_all_keybind = settings.stop_all_keybind;
-            initial_state.compact_mode = settings.compact_mode;
-            initial_state.theme = settings.theme;
-            initial_state.minimize_to_tray = settings.minimize_to_tray;
-            initial_state.overlap_mode = settings.overlap_mode;
-            initial_state.crossfade_duration = settings.crossfade_duration;
+            apply_settings(&mut initial_state, settings);
         }
     }
 
+    let stats_reset_on_start = match initial_state.stats_reset {
+        StatsResetPeriod::OnAppStart => true,
+        period => initial_state.stats.reset_due(period),
+    };
+    if stats_reset_on_start {
+        initial_state.stats.reset_session();
+        save_stats(&initial_state.stats);
+    }
+
+    let engine = Engine::default();
+    sync_runtime_settings(&initial_state, &engine);
+
     // Clone stop all keybind for registering after app starts
     let stop_all_keybind_for_register = initial_state.stop_all_keybind.clone();
+    let mute_keybind_for_register = initial_state.mute_keybind.clone();
+    let quick_search_keybind_for_register = initial_state.quick_search_keybind.clone();
+    let group_step_keybinds_for_register = initial_state.group_step_keybinds.clone();
+    let group_show_keybinds_for_register = initial_state.group_show_keybinds.clone();
 
     // Clone sounds for registering keybinds after app starts
     let sounds_for_keybinds: Vec<(String, String)> = initial_state
@@ -1838,10 +8860,14 @@ fn main() {
     // Create system tray menu
     let show = CustomMenuItem::new("show".to_string(), "Show MotoBoard");
     let stop_all_menu = CustomMenuItem::new("stop_all".to_string(), "Stop All Sounds");
+    let monitor_only_menu = CustomMenuItem::new("monitor_only".to_string(), "Monitor Only");
+    let mute_menu = CustomMenuItem::new("mute".to_string(), "Mute");
     let quit = CustomMenuItem::new("quit".to_string(), "Quit");
     let tray_menu = SystemTrayMenu::new()
         .add_item(show)
         .add_item(stop_all_menu)
+        .add_item(monitor_only_menu)
+        .add_item(mute_menu)
         .add_native_item(SystemTrayMenuItem::Separator)
         .add_item(quit);
     let system_tray = SystemTray::new().with_menu(tray_menu);
@@ -1851,9 +8877,30 @@ fn main() {
         .on_system_tray_event(move |app, event| {
             match event {
                 SystemTrayEvent::LeftClick { .. } => {
-                    if let Some(window) = app.get_window("main") {
-                        let _ = window.show();
-                        let _ = window.set_focus();
+                    let state: State<AppState> = app.state();
+                    let action = state
+                        .lock()
+                        .map(|audio_state| audio_state.tray_left_click_action)
+                        .unwrap_or_default();
+                    match action {
+                        TrayClickAction::Show => {
+                            if let Some(window) = app.get_window("main") {
+                                let _ = window.show();
+                                let _ = window.set_focus();
+                            }
+                        }
+                        TrayClickAction::Toggle => {
+                            if let Some(window) = app.get_window("main") {
+                                if window.is_visible().unwrap_or(false) {
+                                    let _ = window.hide();
+                                } else {
+                                    let _ = window.show();
+                                    let _ = window.set_focus();
+                                }
+                            }
+                        }
+                        TrayClickAction::StopAll => stop_all_playback(StopAllSource::Tray),
+                        TrayClickAction::None => {}
                     }
                 }
                 SystemTrayEvent::MenuItemClick { id, .. } => {
@@ -1865,10 +8912,21 @@ fn main() {
                             }
                         }
                         "stop_all" => {
-                            STOP_ALL_FLAG.store(true, Ordering::SeqCst);
+                            stop_all_playback(StopAllSource::Tray);
+                        }
+                        "monitor_only" => {
+                            let state: State<AppState> = app.state();
+                            if let Ok(mut audio_state) = state.lock() {
+                                let enabled = !audio_state.monitor_only;
+                                let _ = apply_monitor_only(app, &mut audio_state, enabled);
+                            };
+                        }
+                        "mute" => {
+                            toggle_muted();
                         }
                         "quit" => {
-                            std::process::exit(0);
+                            let app_handle = app.clone();
+                            std::thread::spawn(move || shutdown(&app_handle));
                         }
                         _ => {}
                     }
@@ -1877,6 +8935,15 @@ fn main() {
             }
         })
         .on_window_event(move |event| {
+            // A field can't have keyboard focus while the window itself doesn't
+            if let tauri::WindowEvent::Focused(false) = event.event() {
+                TEXT_INPUT_FOCUSED.store(false, Ordering::SeqCst);
+            }
+            // Coming back from another window is when a missed release tends to show up
+            if let tauri::WindowEvent::Focused(true) = event.event() {
+                clear_pressed_keys();
+            }
+
             if let tauri::WindowEvent::CloseRequested { api, .. } = event.event() {
                 // Check current minimize_to_tray setting
                 let should_minimize = if let Ok(state) = audio_state_for_tray.lock() {
@@ -1885,50 +8952,174 @@ fn main() {
                     minimize_to_tray_setting
                 };
 
+                // Either way the window stays until shutdown has flushed everything
+                api.prevent_close();
                 if should_minimize {
                     // Hide window instead of closing
                     let _ = event.window().hide();
-                    api.prevent_close();
+                } else {
+                    let app_handle = event.window().app_handle();
+                    std::thread::spawn(move || shutdown(&app_handle));
                 }
             }
         })
         .manage(audio_state)
+        .manage(engine)
         .invoke_handler(tauri::generate_handler![
             get_audio_devices,
+            get_diagnostics,
+            get_data_versions,
+            get_resource_usage,
             set_primary_device,
+            set_temporary_primary_device,
+            get_temporary_primary_device,
+            set_device_gain,
+            get_device_configs,
+            set_device_config,
+            set_output_sync,
+            set_prebuffer_ms,
+            set_missing_device_behavior,
+            is_primary_device_unavailable,
+            set_file_open_timeout,
+            get_input_devices,
+            identify_devices,
+            set_input_device,
             set_monitor_device,
+            set_extra_devices,
+            set_also_play_default,
+            set_monitor_downmix_mono,
+            set_restore_playback_on_start,
+            set_monitor_only,
             set_master_volume,
             get_sounds,
+            get_visible_sounds,
             get_settings,
+            is_safe_mode,
+            save_safe_mode_board,
             add_sound_from_path,
+            can_add_sound,
+            is_first_run,
+            seed_example_sounds,
             remove_sound,
+            remove_sounds,
+            duplicate_sound,
+            get_recently_added,
+            get_unbound_sounds,
+            migrate_to_managed_library,
+            prune_orphaned_files,
             update_sound_keybind,
+            update_sound,
+            simulate_keybind,
             update_sound_trim,
+            apply_trim_offset,
+            update_sound_trim_samples,
             update_sound_settings,
             update_sound_order,
+            sort_sounds,
+            set_sound_group,
+            rename_group,
+            set_group_order,
+            play_next_in_group,
+            reset_group_cursor,
+            set_group_step_keybind,
+            set_group_show_keybind,
+            set_sound_exclusive_group,
+            set_sound_random_start,
+            set_sound_notify_on_play,
+            set_sound_webhook,
+            set_sound_color,
+            set_sound_window_filter,
+            set_sound_trigger_on_release,
+            get_foreground_window,
             play_sound,
+            play_sound_with_volume,
+            play_sound_on_monitor_only,
+            stop_sound,
+            get_play_stats,
+            reset_play_stats,
+            set_stats_reset,
+            get_effective_volume,
+            preview_trim,
+            get_sound_duration,
+            get_pcm_region,
+            verify_sound,
+            get_sound_info,
+            render_sound,
+            merge_sounds,
+            auto_trim_silence,
             stop_all,
+            force_stop_all,
+            get_stop_all_log,
+            stop_device,
+            set_active_loop,
             add_to_queue,
             remove_from_queue,
             clear_queue,
             get_queue,
             play_queue,
+            audition_all,
             is_queue_playing,
             register_sound_keybind,
+            rebind_sound,
             unregister_sound_keybind,
             register_stop_all_keybind,
             unregister_stop_all_keybind,
             set_stop_all_keybind,
+            toggle_mute,
+            verify_all_shortcuts,
+            is_muted,
+            set_mute_keybind,
+            set_quick_search_keybind,
+            set_key_mapping,
+            set_ignore_key_repeat,
+            set_normalize_lock_keys,
+            set_ignore_when_typing,
+            set_require_modifier_for_alnum,
+            set_text_input_focused,
             set_compact_mode,
+            set_compact_hide_unbound,
+            set_last_import_dir,
+            set_stale_key_timeout,
+            set_max_play_seconds,
+            set_grid_columns,
             set_theme,
             set_minimize_to_tray,
+            set_tray_left_click_action,
             set_overlap_mode,
+            set_default_sound_volume,
             set_crossfade_duration,
+            set_playback_threads,
+            set_max_concurrent,
             get_current_version,
             check_for_updates,
             install_update,
+            set_update_channel,
+            set_auto_check_updates,
+            skip_update_version,
+            clear_skipped_updates,
+            get_release_notes,
             get_last_key_press,
+            suspend_triggers,
+            resume_triggers,
+            are_triggers_suspended,
             get_registered_keybinds,
+            normalize_keybind_public,
+            keybind_to_accelerator,
+            get_last_playback_error,
+            export_keybinds,
+            import_keybinds,
+            bulk_assign_keybinds,
+            import_soundpad,
+            import_csv,
+            export_csv,
+            export_stats_csv,
+            export_cheatsheet,
+            export_sound,
+            import_sound_bundle,
+            create_snapshot,
+            restore_snapshot,
+            clone_profile,
+            reload_from_disk,
         ])
         .setup(move |app| {
             // Store app handle globally for use in shortcut callbacks
@@ -1941,6 +9132,16 @@ fn main() {
 
             // Start the low-level keyboard listener (for games without anti-cheat)
             start_keyboard_listener();
+            start_pressed_key_sweeper();
+
+            start_stats_reset_timer();
+            start_device_watcher();
+            start_play_cap_watcher();
+            start_resource_usage_emitter();
+            start_auto_update_check(app.handle());
+            if stats_reset_on_start {
+                emit_stats_reset();
+            }
 
             // Register existing keybinds with BOTH systems
             let mut shortcut_manager = app.global_shortcut_manager();
@@ -1968,13 +9169,338 @@ fn main() {
                     // Add to GlobalShortcutManager
                     let accelerator = convert_keybind_to_accelerator(&keybind);
                     let _ = shortcut_manager.register(&accelerator, || {
-                        STOP_ALL_FLAG.store(true, Ordering::SeqCst);
+                        if !triggers_suspended() {
+                            stop_all_playback(StopAllSource::Shortcut);
+                        }
                     });
                 }
+
+                // Reserved panic bind, a last resort when the regular stop-all bind misfires
+                registry.insert(PANIC_KEYBIND.to_string(), "PANIC".to_string());
+                let _ = shortcut_manager.register(&convert_keybind_to_accelerator(PANIC_KEYBIND), || {
+                    stop_all_playback(StopAllSource::Panic);
+                });
+            }
+
+            if let Some(keybind) = mute_keybind_for_register {
+                let _ = register_mute_shortcut(&app.handle(), &keybind);
             }
+            if let Some(keybind) = quick_search_keybind_for_register {
+                let _ = register_quick_search_shortcut(&app.handle(), &keybind);
+            }
+            for (group, keybind) in group_step_keybinds_for_register {
+                let _ = register_group_step_shortcut(&app.handle(), &group, &keybind);
+            }
+            for (group, keybind) in group_show_keybinds_for_register {
+                let _ = register_group_show_shortcut(&app.handle(), &group, &keybind);
+            }
+
+            restore_playback(&app.handle());
 
             Ok(())
         })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn mashing_a_pad_never_runs_more_players_than_the_pool() {
+        const SIZE: usize = 4;
+        const TRIGGERS: usize = 100;
+        let pool: &'static PlaybackPool = Box::leak(Box::new(PlaybackPool::new(SIZE)));
+        let engine = Arc::new(AudioEngine::default());
+        // A missing file fails before any output is opened, so the test stays silent
+        let mut sound = new_sound("Airhorn".to_string(), "missing/airhorn.mp3".to_string(), 0, 1.0);
+        sound.echo_delay = 0.2;
+        sound.echo_volume = 0.5;
+        sound.reverb_decay = 0.5;
+        sound.bass_boost = 1.0;
+        let plan = PlaybackPlan::new(&sound, 1.0);
+        let targets = [None, Some("Monitor".to_string())];
+
+        let mut waited = 0;
+        for _ in 0..TRIGGERS {
+            if !engine.queue_playback_on(pool, &plan, &targets) {
+                waited += 1;
+            }
+            assert!(pool.state.lock().unwrap().workers <= SIZE);
+        }
+        assert!(waited > 0, "mashing should have filled the pool");
+
+        // Plays that had to wait still run once workers free up
+        let deadline = std::time::Instant::now() + Duration::from_secs(10);
+        while !pool.state.lock().unwrap().jobs.is_empty() {
+            assert!(std::time::Instant::now() < deadline, "pool stalled");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(pool.state.lock().unwrap().workers <= SIZE);
+    }
+
+    fn pressed(keys: &[&str]) -> HashSet<String> {
+        keys.iter().map(|k| k.to_string()).collect()
+    }
+
+    // AZERTY: the physical Q/W/1 keys type A, Z and &
+    fn azerty() -> HashMap<String, String> {
+        [("Q", "A"), ("W", "Z"), ("1", "&")]
+            .into_iter()
+            .map(|(k, c)| (k.to_string(), c.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn translate_key_follows_mapping() {
+        let layout = azerty();
+        assert_eq!(translate_key("Q", &layout, KeyMapping::Physical), "Q");
+        assert_eq!(translate_key("Q", &layout, KeyMapping::Layout), "A");
+        assert_eq!(translate_key("1", &layout, KeyMapping::Layout), "&");
+        // Keys the layout hasn't reported a character for keep their physical name
+        assert_eq!(translate_key("F5", &layout, KeyMapping::Layout), "F5");
+    }
+
+    #[test]
+    fn pressed_combo_under_physical_mapping() {
+        let layout = azerty();
+        assert_eq!(pressed_combo(&pressed(&["Q"]), &layout, KeyMapping::Physical).as_deref(), Some("Q"));
+        assert_eq!(
+            pressed_combo(&pressed(&["SHIFT", "W", "CTRL"]), &layout, KeyMapping::Physical).as_deref(),
+            Some("Ctrl+Shift+W")
+        );
+    }
+
+    #[test]
+    fn pressed_combo_under_layout_mapping() {
+        let layout = azerty();
+        assert_eq!(pressed_combo(&pressed(&["Q"]), &layout, KeyMapping::Layout).as_deref(), Some("A"));
+        assert_eq!(
+            pressed_combo(&pressed(&["ALT", "CTRL", "W"]), &layout, KeyMapping::Layout).as_deref(),
+            Some("Alt+Ctrl+Z")
+        );
+        assert_eq!(pressed_combo(&pressed(&["1"]), &layout, KeyMapping::Layout).as_deref(), Some("&"));
+    }
+
+    #[test]
+    fn pressed_combo_needs_a_main_key() {
+        let layout = azerty();
+        assert_eq!(pressed_combo(&pressed(&["CTRL", "SHIFT"]), &layout, KeyMapping::Layout), None);
+        assert_eq!(pressed_combo(&pressed(&[]), &layout, KeyMapping::Physical), None);
+    }
+
+    #[test]
+    fn numlock_off_combo_maps_navigation_keys_to_numpad() {
+        assert_eq!(numlock_off_combo("END").as_deref(), Some("NUMPAD1"));
+        assert_eq!(numlock_off_combo("ARROWUP").as_deref(), Some("NUMPAD8"));
+        assert_eq!(numlock_off_combo("Ctrl+END").as_deref(), Some("Ctrl+NUMPAD1"));
+        assert_eq!(numlock_off_combo("Alt+Shift+ARROWUP").as_deref(), Some("Alt+Shift+NUMPAD8"));
+    }
+
+    #[test]
+    fn numlock_off_combo_ignores_other_keys() {
+        assert_eq!(numlock_off_combo("A"), None);
+        assert_eq!(numlock_off_combo("Ctrl+F5"), None);
+        assert_eq!(numlock_off_combo("NUMPAD1"), None);
+    }
+
+    fn board_with_reserved_binds() -> AudioState {
+        let mut state = AudioState {
+            stop_all_keybind: Some("Ctrl+Shift+S".to_string()),
+            mute_keybind: Some("Ctrl+M".to_string()),
+            ..AudioState::default()
+        };
+        let sound = new_sound("Airhorn".to_string(), "airhorn.mp3".to_string(), 0, 1.0);
+        state.sounds.insert(sound.id.clone(), Sound { keybind: Some("F1".to_string()), ..sound });
+        state
+    }
+
+    #[test]
+    fn keybind_conflicts_name_the_reserved_action() {
+        let state = board_with_reserved_binds();
+        let none = HashSet::new();
+        assert_eq!(find_keybind_conflict(&state, "Ctrl+Shift+S", &none).as_deref(), Some("Stop All"));
+        assert_eq!(find_keybind_conflict(&state, "Ctrl+M", &none).as_deref(), Some("Mute"));
+        assert_eq!(find_keybind_conflict(&state, PANIC_KEYBIND, &none).as_deref(), Some("Panic Stop"));
+        assert_eq!(find_keybind_conflict(&state, "F1", &none).as_deref(), Some("Airhorn"));
+        assert_eq!(find_keybind_conflict(&state, "F2", &none), None);
+    }
+
+    #[test]
+    fn keybind_conflicts_ignore_modifier_order() {
+        let state = board_with_reserved_binds();
+        assert_eq!(find_keybind_conflict(&state, "Shift+Ctrl+S", &HashSet::new()).as_deref(), Some("Stop All"));
+    }
+
+    #[test]
+    fn keybind_conflicts_skip_ignored_actions() {
+        let state = board_with_reserved_binds();
+        let ignore = |action: &str| HashSet::from([action.to_string()]);
+        assert_eq!(find_keybind_conflict(&state, "Ctrl+Shift+S", &ignore("STOP_ALL")), None);
+        assert_eq!(find_keybind_conflict(&state, "Ctrl+M", &ignore("MUTE")), None);
+        assert_eq!(find_keybind_conflict(&state, PANIC_KEYBIND, &ignore("PANIC")), None);
+        // Ignoring one action doesn't hide the others
+        assert_eq!(find_keybind_conflict(&state, "Ctrl+M", &ignore("STOP_ALL")).as_deref(), Some("Mute"));
+        let sound_id = state.sounds.keys().next().unwrap().clone();
+        assert_eq!(find_keybind_conflict(&state, "F1", &ignore(&sound_id)), None);
+    }
+
+    #[test]
+    fn soundpad_hotkeys_come_out_normalized() {
+        assert_eq!(convert_soundpad_hotkey("CONTROL+ALT+NUM 1").as_deref(), Ok("Alt+Ctrl+NUMPAD1"));
+        assert_eq!(convert_soundpad_hotkey("SHIFT+NUM ENTER").as_deref(), Ok("Shift+ENTER"));
+        assert_eq!(convert_soundpad_hotkey("LWIN + PGUP").as_deref(), Ok("Super+PAGEUP"));
+    }
+
+    #[test]
+    fn soundpad_hotkeys_we_cant_bind_are_rejected() {
+        assert!(convert_soundpad_hotkey("NUM LOCK").is_err());
+        assert!(convert_soundpad_hotkey("CONTROL+").is_err());
+        assert!(convert_soundpad_hotkey("").is_err());
+    }
+
+    #[test]
+    fn stopping_a_sound_cancels_only_its_pending_plays() {
+        let engine = AudioEngine::default();
+        let airhorn = engine.play_generation("airhorn");
+        let bruh = engine.play_generation("bruh");
+        engine.stop_sounds(&HashSet::from(["airhorn".to_string()]));
+        assert!(engine.play_stopped("airhorn", airhorn));
+        assert!(!engine.play_stopped("bruh", bruh));
+        // A trigger after the stop plays as usual
+        assert!(!engine.play_stopped("airhorn", engine.play_generation("airhorn")));
+    }
+
+    #[test]
+    fn group_binds_follow_a_rename() {
+        let mut binds = HashMap::from([("Memes".to_string(), "F5".to_string())]);
+        assert_eq!(move_group_bind(&mut binds, "Memes", "Classics"), (Some("F5".to_string()), None));
+        assert_eq!(binds, HashMap::from([("Classics".to_string(), "F5".to_string())]));
+        assert_eq!(move_group_bind(&mut binds, "Music", "Classics"), (None, None));
+    }
+
+    #[test]
+    fn merged_group_keeps_the_target_bind() {
+        let mut binds = HashMap::from([
+            ("Memes".to_string(), "F5".to_string()),
+            ("Classics".to_string(), "F6".to_string()),
+        ]);
+        assert_eq!(move_group_bind(&mut binds, "Memes", "Classics"), (None, Some("F5".to_string())));
+        assert_eq!(binds, HashMap::from([("Classics".to_string(), "F6".to_string())]));
+    }
+
+    fn test_source(channels: u16, sample_rate: u32) -> Box<dyn Source<Item = i16> + Send> {
+        Box::new(rodio::buffer::SamplesBuffer::new(channels, sample_rate, vec![0i16; sample_rate as usize / 10]))
+    }
+
+    #[test]
+    fn sources_are_converted_to_the_opened_stream_format() {
+        let converted = match_stream_format(test_source(1, 44100), Some((2, 48000)));
+        assert_eq!((converted.channels(), converted.sample_rate()), (2, 48000));
+
+        let unchanged = match_stream_format(test_source(2, 48000), Some((2, 48000)));
+        assert_eq!((unchanged.channels(), unchanged.sample_rate()), (2, 48000));
+    }
+
+    fn loop_with_region(region: LoopRegion, enabled: bool) -> LiveLoop {
+        LiveLoop {
+            current: Box::new(rodio::buffer::SamplesBuffer::new(1, 100, vec![1i16, 2])),
+            control: LoopControl {
+                enabled: Arc::new(AtomicBool::new(enabled)),
+                region: Arc::new(Mutex::new(region)),
+                file_path: String::new(),
+                trim: Trim::default(),
+            },
+            silence: 0,
+        }
+    }
+
+    fn decoded(samples: &[i16]) -> LoopRegion {
+        LoopRegion::Ready(DecodedRegion { samples: samples.into(), channels: 1, sample_rate: 100 })
+    }
+
+    #[test]
+    fn live_loop_repeats_the_decoded_region() {
+        let samples: Vec<i16> = loop_with_region(decoded(&[5, 6]), true).take(6).collect();
+        assert_eq!(samples, [1, 2, 5, 6, 5, 6]);
+    }
+
+    #[test]
+    fn live_loop_ends_when_looping_is_off() {
+        let samples: Vec<i16> = loop_with_region(decoded(&[5, 6]), false).collect();
+        assert_eq!(samples, [1, 2]);
+    }
+
+    #[test]
+    fn live_loop_bridges_a_pending_region_with_silence() {
+        let mut live = loop_with_region(LoopRegion::Pending, true);
+        let first: Vec<i16> = live.by_ref().take(3).collect();
+        assert_eq!(first, [1, 2, 0]);
+        // At 100 Hz mono the gap is a single sample, so the repeat starts right after it
+        *live.control.region.lock().unwrap() = decoded(&[5, 6]);
+        let rest: Vec<i16> = live.take(2).collect();
+        assert_eq!(rest, [5, 6]);
+    }
+
+    #[test]
+    fn live_loop_stops_when_the_region_fails_to_decode() {
+        let samples: Vec<i16> = loop_with_region(LoopRegion::Failed, true).collect();
+        assert_eq!(samples, [1, 2]);
+    }
+
+    #[test]
+    fn stalled_paths_are_refused_until_their_open_returns() {
+        let path = "stalled-share/sound.mp3";
+        STALLED_OPENS.lock().unwrap().insert(path.to_string());
+        assert!(matches!(open_decoder(path), Err(PlaybackError::FileTimeout(_))));
+        STALLED_OPENS.lock().unwrap().remove(path);
+        assert!(matches!(open_decoder(path), Err(PlaybackError::File(_))));
+    }
+
+    // Both fixtures hold 0.25s of a 440 Hz tone, mono Opus with the usual 312 samples of pre-skip
+    fn assert_decodes_tone_fixture(name: &str) {
+        let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+        let path = path.as_str();
+        check_supported_audio(std::path::Path::new(path)).unwrap();
+
+        let decoder = open_decoder(path).unwrap();
+        assert_eq!((decoder.channels(), decoder.sample_rate()), (1, 48000));
+        let duration = decoder.total_duration().unwrap().as_secs_f64();
+        assert!((duration - 0.25).abs() < 0.001, "duration {}", duration);
+
+        let samples: Vec<i16> = decoder.collect();
+        assert_eq!(samples.len(), 12000);
+        let peak = samples.iter().map(|s| s.saturating_abs()).max().unwrap();
+        assert!(peak > 5000, "peak {}", peak);
+    }
+
+    #[test]
+    fn ogg_opus_fixture_decodes_with_its_duration() {
+        assert_decodes_tone_fixture("tone.opus");
+    }
+
+    #[test]
+    fn webm_opus_fixture_decodes_with_its_duration() {
+        // Muxed with a 250ms segment duration, which is where its length comes from
+        assert_decodes_tone_fixture("tone.webm");
+    }
+
+    #[test]
+    fn opus_packet_frames_follow_the_toc() {
+        // CELT 20ms, one frame
+        assert_eq!(opus_packet_frames(&[31 << 3]), 960);
+        // SILK 60ms, two equal frames
+        assert_eq!(opus_packet_frames(&[(3 << 3) | 1]), 5760);
+        // Hybrid 10ms, code 3 with three frames
+        assert_eq!(opus_packet_frames(&[(12 << 3) | 3, 3]), 1440);
+        assert_eq!(opus_packet_frames(&[]), 0);
+    }
+
+    #[test]
+    fn unknown_stream_format_leaves_the_source_alone() {
+        let source = match_stream_format(test_source(1, 22050), None);
+        assert_eq!((source.channels(), source.sample_rate()), (1, 22050));
+    }
+}
@@ -176,6 +176,28 @@ function App() {
     return () => window.removeEventListener('keydown', handleGlobalKeyDown)
   }, [handleGlobalKeyDown])
 
+  // Tell the backend when a text field has focus so bare single-key binds can be ignored
+  useEffect(() => {
+    // Sliders, checkboxes and the like don't take typed text, so they shouldn't mute binds
+    const textInputTypes = ['text', 'search', 'number', 'email', 'password', 'tel', 'url']
+    const isEditable = (el: EventTarget | null) =>
+      (el instanceof HTMLInputElement && textInputTypes.includes(el.type)) ||
+      el instanceof HTMLTextAreaElement ||
+      (el instanceof HTMLElement && el.isContentEditable)
+    const onFocusIn = (e: FocusEvent) => {
+      if (isEditable(e.target)) invoke('set_text_input_focused', { focused: true }).catch(() => {})
+    }
+    const onFocusOut = (e: FocusEvent) => {
+      if (isEditable(e.target)) invoke('set_text_input_focused', { focused: false }).catch(() => {})
+    }
+    document.addEventListener('focusin', onFocusIn)
+    document.addEventListener('focusout', onFocusOut)
+    return () => {
+      document.removeEventListener('focusin', onFocusIn)
+      document.removeEventListener('focusout', onFocusOut)
+    }
+  }, [])
+
   // Check for updates
   const checkForUpdates = async () => {
     let version = '1.0.0'
@@ -458,11 +480,13 @@ function App() {
   const addSound = async () => {
     try {
       addLog('[Sounds] Opening file dialog...', 'debug')
+      const { lastImportDir } = await invoke<{ lastImportDir?: string | null }>('get_settings')
       const selected = await open({
         multiple: true,
+        defaultPath: lastImportDir ?? undefined,
         filters: [{
           name: 'Audio',
-          extensions: ['mp3', 'wav', 'ogg', 'flac']
+          extensions: ['mp3', 'wav', 'ogg', 'flac', 'webm', 'mka', 'opus']
         }]
       })
 